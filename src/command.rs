@@ -0,0 +1,202 @@
+use crate::core::{ActionId, Alias, CharacterId, ItemId, RoomId};
+
+const GO_VERBS: &[&str] = &["go", "walk", "move", "head"];
+const TALK_VERBS: &[&str] = &["talk", "speak", "chat"];
+const EXAMINE_VERBS: &[&str] = &["examine", "inspect", "look", "x"];
+const LOOK_WORDS: &[&str] = &["look", "l"];
+const INVENTORY_WORDS: &[&str] = &["inventory", "inv", "i"];
+const JOURNAL_WORDS: &[&str] = &["journal", "quests", "j"];
+const HELP_WORDS: &[&str] = &["help", "h"];
+const FILLER_WORDS: &[&str] = &["the", "a", "an", "to", "at", "with"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Go(RoomId),
+    Talk(CharacterId),
+    Do(ActionId),
+    Examine(ItemId),
+    Look,
+    Inventory,
+    Journal,
+    Help,
+    Unknown,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    name: String,
+    aliases: Vec<String>,
+    value: T,
+}
+
+impl<T> Entry<T> {
+    fn new(name: &str, aliases: &[Alias], value: T) -> Self {
+        Self {
+            name: normalize(name),
+            aliases: aliases.iter().map(|a| normalize(a.as_ref())).collect(),
+            value,
+        }
+    }
+    fn matches(&self, noun: &str) -> bool {
+        self.name == noun || self.aliases.iter().any(|a| a == noun)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Vocabulary {
+    exits: Vec<Entry<RoomId>>,
+    characters: Vec<Entry<CharacterId>>,
+    actions: Vec<Entry<ActionId>>,
+    items: Vec<Entry<ItemId>>,
+}
+
+impl Vocabulary {
+    pub fn exit(mut self, direction: &str, aliases: &[Alias], room: RoomId) -> Self {
+        self.exits.push(Entry::new(direction, aliases, room));
+        self
+    }
+    pub fn character(mut self, name: &str, aliases: &[Alias], character: CharacterId) -> Self {
+        self.characters.push(Entry::new(name, aliases, character));
+        self
+    }
+    pub fn action(mut self, name: &str, aliases: &[Alias], action: ActionId) -> Self {
+        self.actions.push(Entry::new(name, aliases, action));
+        self
+    }
+    pub fn item(mut self, name: &str, aliases: &[Alias], item: ItemId) -> Self {
+        self.items.push(Entry::new(name, aliases, item));
+        self
+    }
+    pub fn parse(&self, input: &str) -> Command {
+        let input = normalize(input);
+        if INVENTORY_WORDS.contains(&input.as_str()) {
+            return Command::Inventory;
+        }
+        if JOURNAL_WORDS.contains(&input.as_str()) {
+            return Command::Journal;
+        }
+        if LOOK_WORDS.contains(&input.as_str()) {
+            return Command::Look;
+        }
+        if HELP_WORDS.contains(&input.as_str()) {
+            return Command::Help;
+        }
+        let (verb, noun) = input.split_once(' ').unwrap_or((input.as_str(), ""));
+        if GO_VERBS.contains(&verb)
+            && let Some(room) = find(&self.exits, noun)
+        {
+            return Command::Go(room);
+        }
+        if TALK_VERBS.contains(&verb)
+            && let Some(character) = find(&self.characters, noun)
+        {
+            return Command::Talk(character);
+        }
+        if EXAMINE_VERBS.contains(&verb)
+            && let Some(item) = find(&self.items, noun)
+        {
+            return Command::Examine(item);
+        }
+        if let Some(room) = find(&self.exits, &input) {
+            return Command::Go(room);
+        }
+        if let Some(action) = find(&self.actions, &input) {
+            return Command::Do(action);
+        }
+        Command::Unknown
+    }
+    pub fn hints(&self) -> Vec<String> {
+        self.exits
+            .iter()
+            .map(|e| format!("go {}", e.name))
+            .chain(
+                self.characters
+                    .iter()
+                    .map(|e| format!("talk to {}", e.name)),
+            )
+            .chain(self.actions.iter().map(|e| e.name.clone()))
+            .chain(self.items.iter().map(|e| format!("examine {}", e.name)))
+            .chain(["look", "inventory"].map(String::from))
+            .collect()
+    }
+}
+
+fn find<T: Copy>(entries: &[Entry<T>], noun: &str) -> Option<T> {
+    entries.iter().find(|e| e.matches(noun)).map(|e| e.value)
+}
+
+fn normalize(input: &str) -> String {
+    input
+        .to_lowercase()
+        .replace(['_', '-'], " ")
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .filter(|word| !FILLER_WORDS.contains(word))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn vocabulary() -> Vocabulary {
+        let aliases = |list: &[&str]| {
+            list.iter()
+                .map(|a| a.parse::<Alias>().unwrap())
+                .collect::<Vec<_>>()
+        };
+        Vocabulary::default()
+            .exit("north", &aliases(&["n"]), 1.into())
+            .exit("downstairs", &aliases(&["down", "d", "stairs"]), 2.into())
+            .character("Old Man", &aliases(&["grandpa"]), 0.into())
+            .action("turn_valve", &aliases(&["twist valve"]), 3.into())
+            .item("rusty_key", &aliases(&["key"]), 4.into())
+    }
+
+    #[rstest]
+    #[case::go_direction("go north", Command::Go(1.into()))]
+    #[case::bare_direction("Downstairs", Command::Go(2.into()))]
+    #[case::talk_to("talk to the old man", Command::Talk(0.into()))]
+    #[case::speak("speak old-man", Command::Talk(0.into()))]
+    #[case::action("turn valve", Command::Do(3.into()))]
+    #[case::action_with_filler("Turn the valve!", Command::Do(3.into()))]
+    #[case::examine("look at the rusty key", Command::Examine(4.into()))]
+    #[case::look("look", Command::Look)]
+    #[case::inventory("i", Command::Inventory)]
+    #[case::journal("journal", Command::Journal)]
+    #[case::help("help", Command::Help)]
+    #[case::exit_alias("go stairs", Command::Go(2.into()))]
+    #[case::bare_exit_alias("n", Command::Go(1.into()))]
+    #[case::character_alias("talk to grandpa", Command::Talk(0.into()))]
+    #[case::action_alias("twist the valve", Command::Do(3.into()))]
+    #[case::item_alias("x key", Command::Examine(4.into()))]
+    #[case::unknown_exit("go south", Command::Unknown)]
+    #[case::unknown_verb("dance", Command::Unknown)]
+    #[case::empty("", Command::Unknown)]
+    fn parse_command(vocabulary: Vocabulary, #[case] input: &str, #[case] expected: Command) {
+        assert_eq!(vocabulary.parse(input), expected, "Failed for `{input}`");
+    }
+
+    #[rstest]
+    fn hints_list_available_commands(vocabulary: Vocabulary) {
+        assert_eq!(
+            vocabulary.hints(),
+            vec![
+                "go north",
+                "go downstairs",
+                "talk to old man",
+                "turn valve",
+                "examine rusty key",
+                "look",
+                "inventory",
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use ini::SectionIter;
+
+use crate::{
+    config_parser::{
+        iter::{EntitySection, SectionRecordIter},
+        requirement::parse_requirements,
+    },
+    core::{AchievementRaw, Title},
+    error,
+};
+
+pub fn parse_achievements(
+    ini_iter: SectionIter,
+) -> Result<Vec<AchievementRaw>, error::Application> {
+    let mut list = Vec::new();
+    for record in SectionRecordIter::new(ini_iter, EntitySection::Achievement) {
+        let record = record?.into_record(&["description", "requires"], &[])?;
+        let description = record.require("description")?.to_string();
+        let requires = parse_requirements(&record)?;
+        let name = record.parse_name::<Title>()?;
+        list.push(AchievementRaw {
+            name,
+            description,
+            requires,
+        });
+    }
+    Ok(list)
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use ini::Ini;
+
+    use super::*;
+    use asserting::prelude::*;
+
+    const GOOD_DATA: &str = r"
+        [Achievement:TreasureHunter]
+        description=Found the hidden treasure.
+        requires=has_item:treasure
+
+        [Achievement:Pacifist]
+        description=Finished the game without drawing your sword.
+        requires=does_not_have:sword,visited:Exit
+    ";
+
+    const BAD_DATA_MISSING_REQUIRES: &str = r"
+        [Achievement:TreasureHunter]
+        description=Found the hidden treasure.
+    ";
+
+    #[test]
+    fn parse_achievements_successfully() {
+        let ini = Ini::load_from_str(GOOD_DATA).unwrap();
+        let achievements = parse_achievements(ini.iter()).unwrap();
+        assert_that!(&achievements)
+            .has_length(2)
+            .satisfies_with_message("has expected names", |a| {
+                a[0].name.to_string() == "Treasure Hunter" && a[1].name.to_string() == "Pacifist"
+            })
+            .satisfies_with_message("has requirements", |a| {
+                a[0].requires.len() == 1 && a[1].requires.len() == 2
+            });
+    }
+
+    #[test]
+    fn parse_achievements_missing_requires() {
+        let ini = Ini::load_from_str(BAD_DATA_MISSING_REQUIRES).unwrap();
+        let achievements = parse_achievements(ini.iter());
+        assert_that!(achievements)
+            .is_err()
+            .extracting(|e| e.err().unwrap().to_string())
+            .contains("Missing")
+            .contains("requires");
+    }
+}
@@ -3,13 +3,14 @@ use ini::SectionIter;
 use crate::{
     config_parser::iter::{EntitySection, SectionRecordIter},
     core::{
-        ActionRaw, ChangeRoomRaw, GiveItemRaw, Identifier, ReplaceItemRaw, SequenceRaw,
-        TakeItemRaw, TeleportRaw,
+        ActionCommonRaw, ActionRaw, ChangePartyRaw, ChangeQuestRaw, ChangeRoomRaw, ChangeVarRaw,
+        GiveItemRaw, Identifier, QuestStatus, ReplaceItemRaw, SequenceRaw, StartTimerRaw,
+        TakeItemRaw, TeleportRaw, Timing,
     },
     error,
 };
 
-use super::iter::{IterRequireWith, ParseWith, UnverifiedRecord};
+use super::iter::{IterRequireWith, ParseWith, Record, UnverifiedRecord};
 
 type ActionResult = Result<ActionRaw, error::Application>;
 
@@ -29,6 +30,22 @@ pub fn parse_actions<'a>(ini_iter: SectionIter<'a>) -> Result<Vec<ActionRaw>, er
             next_take_item_action(record)
         } else if record.contains_key("sequence") {
             next_sequence_action(record)
+        } else if record.contains_key("start_timer") {
+            next_start_timer_action(record)
+        } else if record.contains_key("join_party") {
+            next_change_party_action(record, "join_party", true)
+        } else if record.contains_key("leave_party") {
+            next_change_party_action(record, "leave_party", false)
+        } else if record.contains_key("change_var") {
+            next_change_var_action(record)
+        } else if record.contains_key("start_quest") {
+            next_change_quest_action(record, "start_quest", QuestStatus::Active)
+        } else if record.contains_key("advance_quest") {
+            next_change_quest_action(record, "advance_quest", QuestStatus::Active)
+        } else if record.contains_key("complete_quest") {
+            next_change_quest_action(record, "complete_quest", QuestStatus::Completed)
+        } else if record.contains_key("fail_quest") {
+            next_change_quest_action(record, "fail_quest", QuestStatus::Failed)
         } else {
             Err(error::EntityDataIncomplete("Action".into()))
         }?;
@@ -38,7 +55,8 @@ pub fn parse_actions<'a>(ini_iter: SectionIter<'a>) -> Result<Vec<ActionRaw>, er
 }
 
 fn next_change_room_action(record: UnverifiedRecord) -> ActionResult {
-    let record = record.into_record(&["change_room", "description"], &["required"])?;
+    let record =
+        record.into_record(&["change_room", "description"], &action_keys(&["required"]))?;
     let (room, variant) = {
         let change_room = record.require("change_room")?;
         let mut parts = change_room.splitn(2, "->");
@@ -56,11 +74,13 @@ fn next_change_room_action(record: UnverifiedRecord) -> ActionResult {
         (room, variant)
     };
     let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
     let required = record.get_parsed("required")?;
     let name = record.parse_name::<Identifier>()?;
     Ok(ActionRaw::ChangeRoom(ChangeRoomRaw {
         name,
         description,
+        common,
         required,
         room,
         variant,
@@ -68,52 +88,60 @@ fn next_change_room_action(record: UnverifiedRecord) -> ActionResult {
 }
 
 fn next_teleport_action(record: UnverifiedRecord) -> ActionResult {
-    let record = record.into_record(&["teleport_to", "description"], &["required"])?;
+    let record =
+        record.into_record(&["teleport_to", "description"], &action_keys(&["required"]))?;
     let room = record.require_parsed("teleport_to")?;
     let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
     let required = record.get_parsed("required")?;
     let name = record.parse_name::<Identifier>()?;
     Ok(ActionRaw::Teleport(TeleportRaw {
         name,
         description,
+        common,
         required,
         room,
     }))
 }
 
 fn next_give_item_action(record: UnverifiedRecord) -> ActionResult {
-    let record = record.into_record(&["give_item", "description"], &[])?;
+    let record = record.into_record(&["give_item", "description"], &action_keys(&[]))?;
     let items = record
         .get_list_parsed("give_item")
         .collect::<Result<Vec<_>, error::Application>>()?;
     let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
     let required = record.get_parsed("required")?;
     let name = record.parse_name::<Identifier>()?;
     Ok(ActionRaw::GiveItem(GiveItemRaw {
         name,
         description,
+        common,
         required,
         items,
     }))
 }
 
 fn next_take_item_action(record: UnverifiedRecord) -> ActionResult {
-    let record = record.into_record(&["take_item", "description"], &["required"])?;
+    let record = record.into_record(&["take_item", "description"], &action_keys(&["required"]))?;
     let items = record
         .get_list_parsed("give_item")
         .collect::<Result<Vec<_>, error::Application>>()?;
     let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
     let name = record.parse_name::<Identifier>()?;
     Ok(ActionRaw::TakeItem(TakeItemRaw {
         name,
         description,
+        common,
         items,
     }))
 }
 
 fn next_replace_item_action(record: UnverifiedRecord) -> ActionResult {
-    let record = record.into_record(&["replace_item", "description"], &[])?;
+    let record = record.into_record(&["replace_item", "description"], &action_keys(&[]))?;
     let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
     let replace_item = record.require("replace_item")?;
     let mut parts = replace_item.splitn(2, "->");
     let original = parts.require_next(&record, "replace_item:<original>")?;
@@ -124,24 +152,185 @@ fn next_replace_item_action(record: UnverifiedRecord) -> ActionResult {
     Ok(ActionRaw::ReplaceItem(ReplaceItemRaw {
         name,
         description,
+        common,
         original,
         replacement,
     }))
 }
 
+fn next_change_quest_action(
+    record: UnverifiedRecord,
+    key: &'static str,
+    status: QuestStatus,
+) -> ActionResult {
+    let record = record.into_record(&[key, "description"], &action_keys(&["required"]))?;
+    let (quest, stage) = {
+        let change_quest = record.require(key)?;
+        let mut parts = change_quest.splitn(2, "->");
+        let quest = parts
+            .require_next(&record, "quest:<QuestName>")?
+            .trim()
+            .parse_with(&record, "quest:<QuestName>")?;
+        let stage = if key == "advance_quest" {
+            Some(parts.require_next(&record, "advance_quest:QuestName-><stage>")?)
+        } else {
+            parts.next()
+        };
+        let stage = match stage {
+            Some(v) => Some(v.trim().parse_with(&record, "quest:QuestName-><stage>")?),
+            None => None,
+        };
+        (quest, stage)
+    };
+    let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
+    let required = record.get_parsed("required")?;
+    let name = record.parse_name::<Identifier>()?;
+    Ok(ActionRaw::ChangeQuest(ChangeQuestRaw {
+        name,
+        description,
+        common,
+        required,
+        quest,
+        stage,
+        status,
+    }))
+}
+
+fn next_start_timer_action(record: UnverifiedRecord) -> ActionResult {
+    let record =
+        record.into_record(&["start_timer", "description"], &action_keys(&["required"]))?;
+    let (action, turns) = {
+        let start_timer = record.require("start_timer")?;
+        let mut parts = start_timer.splitn(2, "->");
+        let action = parts
+            .require_next(&record, "start_timer:<action_id>")?
+            .trim()
+            .parse_with(&record, "start_timer:<action_id>")?;
+        let turns = parts.require_next(&record, "start_timer:action_id-><turns>")?;
+        let turns = parse_turns(&record, turns, "start_timer")?;
+        (action, turns)
+    };
+    let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
+    let required = record.get_parsed("required")?;
+    let name = record.parse_name::<Identifier>()?;
+    Ok(ActionRaw::StartTimer(StartTimerRaw {
+        name,
+        description,
+        common,
+        required,
+        action,
+        turns,
+    }))
+}
+
+fn next_change_party_action(
+    record: UnverifiedRecord,
+    key: &'static str,
+    joins: bool,
+) -> ActionResult {
+    let record = record.into_record(&[key, "description"], &action_keys(&["required"]))?;
+    let character = record.require_parsed(key)?;
+    let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
+    let required = record.get_parsed("required")?;
+    let name = record.parse_name::<Identifier>()?;
+    Ok(ActionRaw::ChangeParty(ChangePartyRaw {
+        name,
+        description,
+        common,
+        required,
+        character,
+        joins,
+    }))
+}
+
+fn next_change_var_action(record: UnverifiedRecord) -> ActionResult {
+    let record = record.into_record(&["change_var", "description"], &action_keys(&["required"]))?;
+    let changes = record
+        .get_list_parsed("change_var")
+        .collect::<Result<Vec<_>, error::Application>>()?;
+    let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
+    let required = record.get_parsed("required")?;
+    let name = record.parse_name::<Identifier>()?;
+    Ok(ActionRaw::ChangeVar(ChangeVarRaw {
+        name,
+        description,
+        common,
+        required,
+        changes,
+    }))
+}
+
 fn next_sequence_action(record: UnverifiedRecord) -> ActionResult {
-    let record = record.into_record(&["sequence", "description"], &["required"])?;
+    let record = record.into_record(&["sequence", "description"], &action_keys(&["required"]))?;
     let actions = record
         .get_list("sequence")
         .map(|s| s.trim().parse_with(&record, "sequence"))
         .collect::<Result<Vec<Identifier>, _>>()?;
     let description = record.require("description")?.to_string();
+    let common = parse_common(&record)?;
     let required = record.get_parsed("required")?;
     let name = record.parse_name::<Identifier>()?;
     Ok(ActionRaw::Sequence(SequenceRaw {
         name,
         description,
+        common,
         required,
         actions,
     }))
 }
+
+const COMMON_KEYS: [&str; 7] = [
+    "repeat",
+    "aliases",
+    "score",
+    "affinity",
+    "after_turns",
+    "every",
+    "duration",
+];
+
+fn action_keys(keys: &[&'static str]) -> Vec<&'static str> {
+    [keys, &COMMON_KEYS].concat()
+}
+
+fn parse_common(record: &Record) -> Result<ActionCommonRaw, error::Application> {
+    Ok(ActionCommonRaw {
+        repeat: record.get_parsed("repeat")?.unwrap_or_default(),
+        aliases: record
+            .get_list_parsed("aliases")
+            .collect::<Result<Vec<_>, error::Application>>()?,
+        score: record.get_parsed("score")?.unwrap_or_default(),
+        affinity: record
+            .get_list_parsed("affinity")
+            .collect::<Result<Vec<_>, error::Application>>()?,
+        timing: parse_timing(record)?,
+        duration: record.get_parsed("duration")?,
+    })
+}
+
+fn parse_timing(record: &Record) -> Result<Option<Timing>, error::Application> {
+    let after = record
+        .get("after_turns")
+        .map(|t| parse_turns(record, t, "after_turns"))
+        .transpose()?;
+    let every = record
+        .get("every")
+        .map(|t| parse_turns(record, t, "every"))
+        .transpose()?;
+    Ok(Timing::new(after, every))
+}
+
+fn parse_turns(record: &Record, turns: &str, field: &str) -> Result<usize, error::Application> {
+    match turns.trim().parse::<usize>() {
+        Ok(turns) if turns > 0 => Ok(turns),
+        _ => Err(error::InvalidPropertyValue {
+            etype: record.entity_type().into(),
+            value: turns.into(),
+            field: field.into(),
+        }),
+    }
+}
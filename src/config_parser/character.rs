@@ -1,21 +1,51 @@
 use ini::SectionIter;
 
 use crate::{
-    core::{CharacterRaw, Title},
+    core::{CharacterRaw, Repeat, Title},
     error,
 };
 
-use super::iter::{EntitySection, SectionRecordIter};
+use super::{
+    iter::{EntitySection, SectionRecordIter},
+    preprocessor::literal_text,
+    requirement::parse_requirements_from,
+};
 
 pub fn parse_characters(ini_iter: SectionIter) -> Result<Vec<CharacterRaw>, error::Application> {
     let mut list = Vec::new();
     for record in SectionRecordIter::new(ini_iter, EntitySection::Character) {
-        let record = record?.into_record(&["start_dialogue"], &[])?;
+        let record = record?.into_record(
+            &["start_dialogue"],
+            &[
+                "aliases",
+                "greet_dialogue",
+                "greet_requires",
+                "greet_repeat",
+                "pronouns",
+                "art",
+            ],
+        )?;
         let start_dialogue = record.require_parsed("start_dialogue")?;
+        let aliases = record
+            .get_list_parsed("aliases")
+            .collect::<Result<Vec<_>, error::Application>>()?;
+        let greet_dialogue = record.get_parsed("greet_dialogue")?;
+        let greet_requires = parse_requirements_from(&record, "greet_requires")?;
+        let greet_repeat = record
+            .get_parsed("greet_repeat")?
+            .unwrap_or(Repeat::Times(1));
+        let pronouns = record.get_parsed("pronouns")?.unwrap_or_default();
+        let art = record.get("art").map(literal_text);
         let name = record.parse_name::<Title>()?;
         list.push(CharacterRaw {
             name,
             start_dialogue,
+            aliases,
+            greet_dialogue,
+            greet_requires,
+            greet_repeat,
+            pronouns,
+            art,
         });
     }
     Ok(list)
@@ -10,12 +10,30 @@ use super::{
 pub fn parse_dialogues(ini_iter: SectionIter) -> Result<Vec<DialogueRaw>, error::Application> {
     let mut list = Vec::new();
     for record in SectionRecordIter::new(ini_iter, EntitySection::Dialogue) {
-        let record = record?.into_record(&["text"], &["response", "requires"])?;
+        let record = record?.into_record(
+            &["text"],
+            &["response", "requires", "fragments", "priority", "speaker"],
+        )?;
         let text = record.require("text")?.to_string();
         let responses = record
             .get_list_parsed("response")
             .collect::<Result<Vec<_>, error::Application>>()?;
         let requires = parse_requirements(&record)?;
+        let fragments = record
+            .get_list_parsed("fragments")
+            .collect::<Result<Vec<_>, error::Application>>()?;
+        let priority = record
+            .get("priority")
+            .map(|p| {
+                p.parse::<i32>().map_err(|_| error::InvalidPropertyValue {
+                    etype: record.entity_type().into(),
+                    value: p.into(),
+                    field: "priority".into(),
+                })
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let speaker = record.get_parsed("speaker")?;
         let name = record.parse_name()?;
         let variant = record.variant().clone();
         list.push(DialogueRaw {
@@ -24,6 +42,9 @@ pub fn parse_dialogues(ini_iter: SectionIter) -> Result<Vec<DialogueRaw>, error:
             text,
             responses,
             requires,
+            fragments,
+            priority,
+            speaker,
         });
     }
     Ok(list)
@@ -0,0 +1,79 @@
+use ini::SectionIter;
+
+use crate::{
+    config_parser::{
+        iter::{EntitySection, SectionRecordIter},
+        requirement::parse_requirements,
+    },
+    core::FragmentRaw,
+    error,
+};
+
+pub fn parse_fragments(ini_iter: SectionIter) -> Result<Vec<FragmentRaw>, error::Application> {
+    let mut list = Vec::new();
+    for record in SectionRecordIter::new(ini_iter, EntitySection::Fragment) {
+        let record = record?.into_record(&["text", "requires"], &[])?;
+        let text = record.require("text")?.to_string();
+        let requires = parse_requirements(&record)?;
+        let name = record.parse_name()?;
+        list.push(FragmentRaw {
+            name,
+            text,
+            requires,
+        });
+    }
+    Ok(list)
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use ini::Ini;
+
+    use crate::config_parser::test_utils::i;
+
+    use super::*;
+    use asserting::prelude::*;
+
+    const GOOD_DATA: &str = r"
+        [Fragment:lamp_lit]
+        text=A lamp flickers on the desk.
+        requires=did:light_lamp
+
+        [Fragment:window_open]
+        text=A breeze blows in through the open window.
+        requires=did:open_window,does_not_have:coat
+    ";
+
+    const BAD_DATA_MISSING_REQUIRES: &str = r"
+        [Fragment:lamp_lit]
+        text=A lamp flickers on the desk.
+    ";
+
+    #[test]
+    fn parse_fragments_successfully() {
+        let ini = Ini::load_from_str(GOOD_DATA).unwrap();
+        let fragments = parse_fragments(ini.iter()).unwrap();
+        assert_that!(&fragments)
+            .has_length(2)
+            .satisfies_with_message("has expected ids", |f| {
+                f[0].name == i("lamp_lit") && f[1].name == i("window_open")
+            })
+            .satisfies_with_message("has requirements", |f| {
+                f[0].requires.len() == 1 && f[1].requires.len() == 2
+            });
+    }
+
+    #[test]
+    fn parse_fragments_missing_requires() {
+        let ini = Ini::load_from_str(BAD_DATA_MISSING_REQUIRES).unwrap();
+        let fragments = parse_fragments(ini.iter());
+        assert_that!(fragments)
+            .is_err()
+            .extracting(|e| e.err().unwrap().to_string())
+            .contains("Missing")
+            .contains("lamp_lit")
+            .contains("requires");
+    }
+}
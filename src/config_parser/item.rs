@@ -1,19 +1,30 @@
 use ini::SectionIter;
 
 use crate::{
-    core::{Identifier, Item},
+    core::{Identifier, ItemRaw},
     error,
 };
 
 use super::iter::{EntitySection, SectionRecordIter};
 
-pub fn parse_items<'a>(ini_iter: SectionIter<'a>) -> Result<Vec<Item>, error::Application> {
+pub fn parse_items<'a>(ini_iter: SectionIter<'a>) -> Result<Vec<ItemRaw>, error::Application> {
     let mut list = Vec::new();
     for record in SectionRecordIter::new(ini_iter, EntitySection::Item) {
-        let record = record?.into_record(&["description"], &[])?;
+        let record = record?.into_record(&["description"], &["fragments", "aliases"])?;
         let description = record.require("description")?.to_string();
+        let fragments = record
+            .get_list_parsed("fragments")
+            .collect::<Result<Vec<_>, error::Application>>()?;
+        let aliases = record
+            .get_list_parsed("aliases")
+            .collect::<Result<Vec<_>, error::Application>>()?;
         let name = record.parse_name::<Identifier>()?;
-        list.push(Item { name, description });
+        list.push(ItemRaw {
+            name,
+            description,
+            fragments,
+            aliases,
+        });
     }
     Ok(list)
 }
@@ -32,6 +43,7 @@ mod test {
     const GOOD_DATA: &str = r"
                 [Item:gold_watch]
                 description=Look how it dazzles in the light!
+                aliases=watch, pocket watch
 
                 [Item:royal_robe]
                 description=Such vibrant hues of purple, red, and gold!
@@ -61,16 +73,25 @@ mod test {
         let items = parse_items(ini.iter()).unwrap();
         assert_eq!(items.len(), 4);
         assert_that!(items).contains_exactly_in_any_order([
-            Item::new(i("gold_watch"), "Look how it dazzles in the light!".into()),
-            Item::new(
+            ItemRaw::new(
+                i("gold_watch"),
+                "Look how it dazzles in the light!".into(),
+                vec![],
+                vec!["watch".parse().unwrap(), "pocket watch".parse().unwrap()],
+            ),
+            ItemRaw::new(
                 i("royal_robe"),
                 "Such vibrant hues of purple, red, and gold!".into(),
+                vec![],
+                vec![],
             ),
-            Item::new(
+            ItemRaw::new(
                 i("rusty_knife"),
                 "Dull and twisty, but quite useful.".into(),
+                vec![],
+                vec![],
             ),
-            Item::new(i("potato_sack"), "This will do...".into()),
+            ItemRaw::new(i("potato_sack"), "This will do...".into(), vec![], vec![]),
         ]);
     }
 
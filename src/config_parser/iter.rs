@@ -10,10 +10,13 @@ use crate::{
 #[derive(IntoStaticStr, EnumString, EnumIter, Hash, Debug, PartialEq, Eq, Clone, Copy)]
 #[strum(serialize_all = "PascalCase")]
 pub enum EntitySection {
+    Achievement,
     Action,
     Character,
     Dialogue,
+    Fragment,
     Item,
+    Quest,
     Response,
     Room,
     Root,
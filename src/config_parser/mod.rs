@@ -1,12 +1,16 @@
+mod achievement;
 mod action;
 mod character;
 mod dialogue;
+mod fragment;
 mod item;
 mod iter;
 mod preprocessor;
+mod quest;
 mod requirement;
 mod response;
 mod room;
+mod template;
 mod title;
 
 #[cfg(test)]
@@ -17,25 +21,31 @@ use std::{
     hash::Hash,
 };
 
+use achievement::parse_achievements;
 use action::parse_actions;
 use character::parse_characters;
 use dialogue::parse_dialogues;
+use fragment::parse_fragments;
 use indexmap::IndexMap;
 use ini::Ini;
 use item::parse_items;
 use iter::EntitySection;
+use quest::parse_quests;
 use response::parse_responses;
 use room::parse_rooms;
 use strum::IntoEnumIterator;
+use template::TemplateChecker;
 use title::{parse_language, parse_theme, parse_title};
 
 use crate::{
     core::{
-        ActionEntity, ActionId, ActionRaw, ChangeRoom, CharacterEntity, CharacterId,
-        DialogueEntity, DialogueId, DialogueRaw, DialogueVariantEntity, DialogueVariantId,
-        GameTitle, GiveItem, Identifier, ItemId, ReplaceItem, Requirement, RequirementRaw,
-        ResponseEntity, ResponseId, RoomId, RoomRaw, RoomVariantEntity, RoomVariantId, Sequence,
-        TakeItem, Teleport, Title, World,
+        AchievementEntity, ActionCommon, ActionCommonRaw, ActionEntity, ActionId, ActionRaw,
+        AffinityChange, Alias, ChangeParty, ChangeQuest, ChangeRoom, ChangeVar, CharacterEntity,
+        CharacterId, DialogueEntity, DialogueId, DialogueRaw, DialogueVariantEntity,
+        DialogueVariantId, FragmentEntity, FragmentId, GameTitle, GiveItem, Identifier, Item,
+        ItemId, QuestId, QuestRaw, QuestStageEntity, QuestStageId, ReplaceItem, Requirement,
+        RequirementRaw, ResponseEntity, ResponseId, RoomId, RoomRaw, RoomVariantEntity,
+        RoomVariantId, Sequence, StartTimer, TakeItem, Teleport, Title, World,
     },
     error,
 };
@@ -53,8 +63,11 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
     let items = parse_items(ini.iter())?;
     let actions = parse_actions(ini.iter())?;
     let responses = parse_responses(ini.iter())?;
+    let fragments = parse_fragments(ini.iter())?;
+    let achievements = parse_achievements(ini.iter())?;
     let raw_rooms = parse_rooms(ini.iter())?; // has variants
     let raw_dialogues = parse_dialogues(ini.iter())?; // has variants
+    let raw_quests = parse_quests(ini.iter())?; // has variants
 
     // map ids
     let character_ids = characters
@@ -77,15 +90,114 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
         .enumerate()
         .map(|(id, v)| (v.name.clone(), id.into()))
         .collect::<HashMap<Identifier, ResponseId>>();
+    let fragment_ids = fragments
+        .iter()
+        .enumerate()
+        .map(|(id, v)| (v.name.clone(), id.into()))
+        .collect::<HashMap<Identifier, FragmentId>>();
     let room_ids = raw_rooms.map_ids();
     let dialouge_ids = raw_dialogues.map_ids();
+    let quest_ids = raw_quests.map_ids();
+
+    // check text templates
+    let vars = actions
+        .iter()
+        .filter_map(|a| match a {
+            ActionRaw::ChangeVar(c) => Some(c.changes.iter().map(|v| v.var().clone())),
+            _ => None,
+        })
+        .flatten()
+        .collect::<HashSet<Identifier>>();
+    let checker = TemplateChecker {
+        items: &item_ids,
+        characters: &character_ids,
+        vars: &vars,
+    };
+    checker.check("Root", "", "greeting", &title.greeting)?;
+    checker.check("Root", "", "credits", &title.credits)?;
+    if let Some(status_bar) = &title.status_bar {
+        checker.check("Root", "", "status_bar", status_bar)?;
+    }
+    for (key, text) in language.clone().strings_mut() {
+        checker.check("Language", "", key, text)?;
+    }
+    for raw in &items {
+        checker.check(
+            "Item",
+            &raw.name.to_string(),
+            "description",
+            &raw.description,
+        )?;
+    }
+    for raw in &actions {
+        checker.check(
+            "Action",
+            &raw.name().to_string(),
+            "description",
+            raw.description(),
+        )?;
+    }
+    for raw in &responses {
+        checker.check("Response", &raw.name.to_string(), "text", &raw.text)?;
+    }
+    for raw in &fragments {
+        checker.check("Fragment", &raw.name.to_string(), "text", &raw.text)?;
+    }
+    for raw in &achievements {
+        checker.check(
+            "Achievement",
+            &raw.name.to_string(),
+            "description",
+            &raw.description,
+        )?;
+    }
+    for raw in &raw_rooms {
+        let id = qualified_name(&raw.name, &raw.variant);
+        checker.check("Room", &id, "description", &raw.description)?;
+        for (field, text) in [
+            ("first_description", &raw.first_description),
+            ("short_description", &raw.short_description),
+        ] {
+            if let Some(text) = text {
+                checker.check("Room", &id, field, text)?;
+            }
+        }
+    }
+    for raw in &raw_dialogues {
+        let id = qualified_name(&raw.name, &raw.variant);
+        checker.check("Dialogue", &id, "text", &raw.text)?;
+    }
+    for raw in &raw_quests {
+        let id = qualified_name(&raw.name, &raw.variant);
+        checker.check("Quest", &id, "description", &raw.description)?;
+    }
 
     // build entities
+    let ctx = ResolveContext {
+        items: &item_ids,
+        rooms: &room_ids,
+        dialogues: &dialouge_ids,
+        actions: &action_ids,
+        responses: &response_ids,
+        quests: &quest_ids,
+        characters: &character_ids,
+    };
     let title = GameTitle::builder()
         .title(title.title)
         .greeting(title.greeting)
         .credits(title.credits)
         .start_room(room_ids.get_id(&title.start_room)?)
+        .show_exhausted(title.show_exhausted)
+        .maybe_max_score(title.max_score)
+        .maybe_start_time(title.start_time)
+        .maybe_minutes_per_turn(title.minutes_per_turn)
+        .maybe_player_name(title.player_name)
+        .maybe_player_pronouns(title.player_pronouns)
+        .ask_player_name(title.ask_player_name)
+        .ask_pronouns(title.ask_pronouns)
+        .maybe_art(title.art)
+        .maybe_log_length(title.log_length)
+        .maybe_status_bar(title.status_bar)
         .build();
     let characters = characters
         .into_iter()
@@ -93,6 +205,15 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
             Ok(CharacterEntity {
                 name: raw.name.to_string(),
                 start_dialogue: dialouge_ids.get_id(&raw.start_dialogue)?,
+                aliases: raw.aliases,
+                greet_dialogue: raw
+                    .greet_dialogue
+                    .map(|d| dialouge_ids.get_id(&d))
+                    .transpose()?,
+                greet_requires: requirements_from_raw(&raw.greet_requires, &ctx)?,
+                greet_repeat: raw.greet_repeat,
+                pronouns: raw.pronouns,
+                art: raw.art,
             })
         })
         .collect::<Result<Vec<_>, error::Application>>()?;
@@ -103,6 +224,7 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                 ActionRaw::ChangeRoom(r) => ActionEntity::ChangeRoom(ChangeRoom {
                     name: r.name.to_string(),
                     description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
                     required: r.required.map(|v| item_ids.require(&v)).transpose()?,
                     room: room_ids.get_id(&r.room)?,
                     variant: room_ids.get_variant_id(&r.room, &r.variant)?,
@@ -110,6 +232,7 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                 ActionRaw::GiveItem(r) => ActionEntity::GiveItem(GiveItem {
                     name: r.name.to_string(),
                     description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
                     required: r.required.map(|v| item_ids.require(&v)).transpose()?,
                     items: r
                         .items
@@ -120,12 +243,14 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                 ActionRaw::ReplaceItem(r) => ActionEntity::ReplaceItem(ReplaceItem {
                     name: r.name.to_string(),
                     description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
                     original: item_ids.require(&r.original)?,
                     replacement: item_ids.require(&r.replacement)?,
                 }),
                 ActionRaw::TakeItem(r) => ActionEntity::TakeItem(TakeItem {
                     name: r.name.to_string(),
                     description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
                     items: r
                         .items
                         .iter()
@@ -135,12 +260,14 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                 ActionRaw::Teleport(r) => ActionEntity::Teleport(Teleport {
                     name: r.name.to_string(),
                     description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
                     required: r.required.map(|v| item_ids.require(&v)).transpose()?,
                     room: room_ids.get_id(&r.room)?,
                 }),
                 ActionRaw::Sequence(r) => ActionEntity::Sequence(Sequence {
                     name: r.name.to_string(),
                     description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
                     required: r.required.map(|v| item_ids.require(&v)).transpose()?,
                     actions: r
                         .actions
@@ -148,6 +275,38 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                         .map(|v| action_ids.require(v))
                         .collect::<Result<Vec<_>, error::Application>>()?,
                 }),
+                ActionRaw::ChangeQuest(r) => ActionEntity::ChangeQuest(ChangeQuest {
+                    name: r.name.to_string(),
+                    description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
+                    required: r.required.map(|v| item_ids.require(&v)).transpose()?,
+                    quest: quest_ids.get_id(&r.quest)?,
+                    stage: quest_ids.get_variant_id(&r.quest, &r.stage)?,
+                    status: r.status,
+                }),
+                ActionRaw::ChangeParty(r) => ActionEntity::ChangeParty(ChangeParty {
+                    name: r.name.to_string(),
+                    description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
+                    required: r.required.map(|v| item_ids.require(&v)).transpose()?,
+                    character: character_ids.require(&r.character)?,
+                    joins: r.joins,
+                }),
+                ActionRaw::ChangeVar(r) => ActionEntity::ChangeVar(ChangeVar {
+                    name: r.name.to_string(),
+                    description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
+                    required: r.required.map(|v| item_ids.require(&v)).transpose()?,
+                    changes: r.changes,
+                }),
+                ActionRaw::StartTimer(r) => ActionEntity::StartTimer(StartTimer {
+                    name: r.name.to_string(),
+                    description: r.description,
+                    common: common_from_raw(r.common, &character_ids)?,
+                    required: r.required.map(|v| item_ids.require(&v)).transpose()?,
+                    action: action_ids.require(&r.action)?,
+                    turns: r.turns,
+                }),
             })
         })
         .collect::<Result<Vec<_>, error::Application>>()?;
@@ -158,27 +317,80 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                 text: raw.text.clone(),
                 leads_to: raw.leads_to.map(|v| dialouge_ids.get_id(&v)).transpose()?,
                 triggers: raw.triggers.map(|v| action_ids.require(&v)).transpose()?,
-                requires: raw
-                    .requires
+                requires: requirements_from_raw(&raw.requires, &ctx)?,
+                repeat: raw.repeat,
+                score: raw.score,
+                affinity: affinity_from_raw(&raw.affinity, &character_ids)?,
+                answer: raw.answer,
+                fail_leads_to: raw
+                    .fail_leads_to
+                    .map(|v| dialouge_ids.get_id(&v))
+                    .transpose()?,
+                fail_triggers: raw
+                    .fail_triggers
+                    .map(|v| action_ids.require(&v))
+                    .transpose()?,
+            })
+        })
+        .collect::<Result<Vec<_>, error::Application>>()?;
+    let fragments = fragments
+        .into_iter()
+        .map(|raw| {
+            Ok(FragmentEntity {
+                text: raw.text,
+                requires: requirements_from_raw(&raw.requires, &ctx)?,
+            })
+        })
+        .collect::<Result<Vec<_>, error::Application>>()?;
+    let achievements = achievements
+        .into_iter()
+        .map(|raw| {
+            Ok(AchievementEntity {
+                name: raw.name.to_string(),
+                description: raw.description,
+                requires: requirements_from_raw(&raw.requires, &ctx)?,
+            })
+        })
+        .collect::<Result<Vec<_>, error::Application>>()?;
+    let items = items
+        .into_iter()
+        .map(|raw| {
+            Ok(Item {
+                name: raw.name,
+                description: raw.description,
+                fragments: raw
+                    .fragments
                     .iter()
-                    .map(|r| requirement_from_raw(r, &item_ids, &room_ids))
+                    .map(|v| fragment_ids.require(v))
                     .collect::<Result<Vec<_>, error::Application>>()?,
+                aliases: raw.aliases,
             })
         })
         .collect::<Result<Vec<_>, error::Application>>()?;
     let mut rooms = Vec::new();
+    let mut room_variant_names = Vec::new();
     for raw in raw_rooms {
         let id: RoomId = room_ids.get_id(&raw.name)?;
         if usize::from(id) + 1 > rooms.len() {
             rooms.push(Vec::new());
+            room_variant_names.push((raw.name.to_string(), Vec::new()));
         }
         #[allow(clippy::expect_used)]
+        room_variant_names
+            .last_mut()
+            .expect("populated vec shouldn't be empty")
+            .1
+            .push(qualified_name(&raw.name, &raw.variant));
+        #[allow(clippy::expect_used)]
         rooms
             .last_mut()
             .expect("populated vec shouldn't be empty")
             .push(RoomVariantEntity {
                 name: raw.name.to_string(),
                 description: raw.description.clone(),
+                first_description: raw.first_description.clone(),
+                short_description: raw.short_description.clone(),
+                art: raw.art.clone(),
                 characters: raw
                     .characters
                     .iter()
@@ -189,38 +401,153 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
                     .iter()
                     .map(|(direction, name)| Ok((direction.clone(), room_ids.get_id(name)?)))
                     .collect::<Result<IndexMap<Identifier, RoomId>, error::Application>>()?,
+                exit_aliases: raw.exit_aliases.clone(),
+                travel_times: raw.travel_times.clone(),
                 actions: raw
                     .actions
                     .iter()
                     .map(|v| action_ids.require(v))
                     .collect::<Result<Vec<_>, error::Application>>()?,
+                fragments: raw
+                    .fragments
+                    .iter()
+                    .map(|v| fragment_ids.require(v))
+                    .collect::<Result<Vec<_>, error::Application>>()?,
+                requires: requirements_from_raw(&raw.requires, &ctx)?,
+                priority: raw.priority,
             });
     }
     let mut dialogues = Vec::new();
+    let mut dialogue_variant_names = Vec::new();
     for raw in raw_dialogues {
         let id: DialogueId = dialouge_ids.get_id(&raw.name)?;
         if usize::from(id) + 1 > dialogues.len() {
             dialogues.push(DialogueEntity::new());
+            dialogue_variant_names.push((raw.name.to_string(), Vec::new()));
         }
         #[allow(clippy::expect_used)]
+        dialogue_variant_names
+            .last_mut()
+            .expect("populated vec shouldn't be empty")
+            .1
+            .push(qualified_name(&raw.name, &raw.variant));
+        #[allow(clippy::expect_used)]
         dialogues
             .last_mut()
             .expect("populated vec shouldn't be empty")
             .push(DialogueVariantEntity {
                 text: raw.text.clone(),
+                priority: raw.priority,
                 responses: raw
                     .responses
                     .iter()
                     .map(|v| response_ids.require(v))
                     .collect::<Result<Vec<_>, error::Application>>()?,
-                requires: raw
-                    .requires
+                requires: requirements_from_raw(&raw.requires, &ctx)?,
+                fragments: raw
+                    .fragments
                     .iter()
-                    .map(|r| requirement_from_raw(r, &item_ids, &room_ids))
+                    .map(|v| fragment_ids.require(v))
                     .collect::<Result<Vec<_>, error::Application>>()?,
+                speaker: raw
+                    .speaker
+                    .as_ref()
+                    .map(|c| character_ids.require(c))
+                    .transpose()?,
             });
     }
 
+    let mut quests = Vec::new();
+    for raw in raw_quests {
+        let id: QuestId = quest_ids.get_id(&raw.name)?;
+        if usize::from(id) + 1 > quests.len() {
+            quests.push(Vec::new());
+        }
+        #[allow(clippy::expect_used)]
+        quests
+            .last_mut()
+            .expect("populated vec shouldn't be empty")
+            .push(QuestStageEntity {
+                name: raw.name.to_string(),
+                description: raw.description,
+            });
+    }
+
+    // Ambiguous variant check
+    for (variants, (id, names)) in rooms.iter().zip(&room_variant_names) {
+        for (i, first) in variants.iter().enumerate() {
+            for (j, second) in variants.iter().enumerate().skip(i + 1) {
+                if first.is_ambiguous_with(second) {
+                    return Err(error::AmbiguousVariants {
+                        etype: "Room".into(),
+                        id: id.as_str().into(),
+                        first: names[i].as_str().into(),
+                        second: names[j].as_str().into(),
+                    });
+                }
+            }
+        }
+    }
+    for (variants, (id, names)) in dialogues.iter().zip(&dialogue_variant_names) {
+        for (i, first) in variants.iter().enumerate() {
+            for (j, second) in variants.iter().enumerate().skip(i + 1) {
+                if first.is_ambiguous_with(second) {
+                    return Err(error::AmbiguousVariants {
+                        etype: "Dialogue".into(),
+                        id: id.as_str().into(),
+                        first: names[i].as_str().into(),
+                        second: names[j].as_str().into(),
+                    });
+                }
+            }
+        }
+    }
+
+    // Duplicate alias check
+    for room in rooms.iter().flatten() {
+        let exits = room.exits.keys().map(|direction| {
+            (
+                format!("exit `{direction}`"),
+                direction.to_string(),
+                room.exit_aliases(direction).collect::<Vec<_>>(),
+            )
+        });
+        let characters = room.characters.iter().map(|id| {
+            let character = &characters[usize::from(id)];
+            (
+                format!("character `{}`", character.name),
+                character.name.clone(),
+                character.aliases.clone(),
+            )
+        });
+        let actions = room.actions.iter().map(|id| {
+            let action = &actions[usize::from(id)];
+            (
+                format!("action `{}`", action.name()),
+                action.name().to_string(),
+                action.common().aliases.clone(),
+            )
+        });
+        let mut owners = HashMap::new();
+        for (owner, name, aliases) in exits.chain(characters).chain(actions) {
+            let names = name
+                .parse::<Alias>()
+                .into_iter()
+                .chain(aliases)
+                .collect::<HashSet<_>>();
+            for alias in names {
+                if let Some(first) = owners.insert(alias.clone(), owner.clone()) {
+                    return Err(error::DuplicateAlias {
+                        room: room.name.as_str().into(),
+                        alias: alias.to_string().into(),
+                        first: first.into(),
+                        second: owner.into(),
+                    });
+                }
+            }
+        }
+    }
+
     // Circular reference check
     for outer in actions.iter() {
         if let ActionEntity::Sequence(s) = &outer {
@@ -246,23 +573,90 @@ pub fn parse(ini: Ini) -> Result<World, error::Application> {
         .dialogues(dialogues)
         .characters(characters)
         .responses(responses)
+        .fragments(fragments)
+        .quests(quests)
+        .achievements(achievements)
         .build())
 }
 
+struct ResolveContext<'a> {
+    items: &'a HashMap<Identifier, ItemId>,
+    rooms: &'a IdMap<RoomRaw>,
+    dialogues: &'a IdMap<DialogueRaw>,
+    actions: &'a HashMap<Identifier, ActionId>,
+    responses: &'a HashMap<Identifier, ResponseId>,
+    quests: &'a IdMap<QuestRaw>,
+    characters: &'a HashMap<Title, CharacterId>,
+}
+
+fn requirements_from_raw(
+    raw: &[RequirementRaw],
+    ctx: &ResolveContext,
+) -> Result<Vec<Requirement>, error::Application> {
+    raw.iter().map(|r| requirement_from_raw(r, ctx)).collect()
+}
+
 fn requirement_from_raw(
     raw: &RequirementRaw,
-    item_ids: &HashMap<Identifier, ItemId>,
-    room_ids: &IdMap<RoomRaw>,
+    ctx: &ResolveContext,
 ) -> Result<Requirement, error::Application> {
     Ok(match raw {
-        RequirementRaw::HasItem(n) => Requirement::HasItem(item_ids.require(n)?),
+        RequirementRaw::HasItem(n) => Requirement::HasItem(ctx.items.require(n)?),
         RequirementRaw::RoomVariant(n, v) => {
-            Requirement::RoomVariant(room_ids.get_id(n)?, room_ids.get_variant_id(n, v)?)
+            Requirement::RoomVariant(ctx.rooms.get_id(n)?, ctx.rooms.get_variant_id(n, v)?)
+        }
+        RequirementRaw::DoesNotHave(n) => Requirement::DoesNotHave(ctx.items.require(n)?),
+        RequirementRaw::Visited(n) => Requirement::Visited(ctx.rooms.get_id(n)?),
+        RequirementRaw::Seen(n) => Requirement::Seen(ctx.dialogues.get_id(n)?),
+        RequirementRaw::Did(n) => Requirement::Did(ctx.actions.require(n)?),
+        RequirementRaw::Chose(n) => Requirement::Chose(ctx.responses.require(n)?),
+        RequirementRaw::QuestStage(n, v) => {
+            Requirement::QuestStage(ctx.quests.get_id(n)?, ctx.quests.get_variant_id(n, v)?)
+        }
+        RequirementRaw::QuestStatus(n, status) => {
+            Requirement::QuestStatus(ctx.quests.get_id(n)?, *status)
+        }
+        RequirementRaw::Turns(range) => Requirement::Turns(*range),
+        RequirementRaw::Time(time) => Requirement::Time(*time),
+        RequirementRaw::PlayerName(name) => Requirement::PlayerName(name.clone()),
+        RequirementRaw::PlayerPronouns(pronouns) => Requirement::PlayerPronouns(pronouns.clone()),
+        RequirementRaw::InParty(n) => Requirement::InParty(ctx.characters.require(n)?),
+        RequirementRaw::Affinity(n, threshold) => {
+            Requirement::Affinity(ctx.characters.require(n)?, *threshold)
         }
-        RequirementRaw::DoesNotHave(n) => Requirement::DoesNotHave(item_ids.require(n)?),
     })
 }
 
+fn affinity_from_raw(
+    raw: &[AffinityChange],
+    character_ids: &HashMap<Title, CharacterId>,
+) -> Result<Vec<(CharacterId, i32)>, error::Application> {
+    raw.iter()
+        .map(|change| Ok((character_ids.require(&change.character)?, change.amount)))
+        .collect()
+}
+
+fn common_from_raw(
+    raw: ActionCommonRaw,
+    character_ids: &HashMap<Title, CharacterId>,
+) -> Result<ActionCommon, error::Application> {
+    Ok(ActionCommon {
+        affinity: affinity_from_raw(&raw.affinity, character_ids)?,
+        repeat: raw.repeat,
+        aliases: raw.aliases,
+        score: raw.score,
+        timing: raw.timing,
+        duration: raw.duration,
+    })
+}
+
+fn qualified_name(name: &impl ToString, variant: &Option<Identifier>) -> String {
+    variant.as_ref().map_or_else(
+        || name.to_string(),
+        |variant| format!("{}:{variant}", name.to_string()),
+    )
+}
+
 trait HasEntityType {
     fn entity_type() -> &'static str;
 }
@@ -296,6 +690,11 @@ impl HasEntityType for DialogueVariantId {
         "DialogueVariant"
     }
 }
+impl HasEntityType for FragmentId {
+    fn entity_type() -> &'static str {
+        "Fragment"
+    }
+}
 impl HasEntityType for RoomId {
     fn entity_type() -> &'static str {
         "Room"
@@ -306,6 +705,16 @@ impl HasEntityType for RoomVariantId {
         "RoomVariant"
     }
 }
+impl HasEntityType for QuestId {
+    fn entity_type() -> &'static str {
+        "Quest"
+    }
+}
+impl HasEntityType for QuestStageId {
+    fn entity_type() -> &'static str {
+        "QuestStage"
+    }
+}
 trait RequireClone<K, V> {
     fn require(&self, key: &K) -> Result<V, error::Application>;
 }
@@ -470,6 +879,52 @@ impl HasIdAndVariantId<RoomRaw> for IdMap<RoomRaw> {
         })
     }
 }
+impl HasIdAndVariantId<QuestRaw> for IdMap<QuestRaw> {
+    fn get_id(
+        &self,
+        name: &<QuestRaw as HasNameVariant>::Name,
+    ) -> Result<<QuestRaw as HasNameVariant>::Id, error::Application> {
+        Ok(self
+            .get(name)
+            .ok_or_else(|| error::EntityNotFound {
+                etype: "Quest".into(),
+                id: name.to_string().into(),
+            })?
+            .get(&None)
+            .ok_or_else(|| error::DefaultEntityNotFound {
+                etype: "Quest default".into(),
+                id: name.to_string().into(),
+            })?
+            .0)
+    }
+
+    fn get_variant_id(
+        &self,
+        name: &<QuestRaw as HasNameVariant>::Name,
+        variant: &Option<Identifier>,
+    ) -> Result<Option<<QuestRaw as HasNameVariant>::VariantId>, error::Application> {
+        Ok(if variant.is_none() {
+            None
+        } else {
+            Some(
+                self.get(name)
+                    .ok_or_else(|| error::EntityNotFound {
+                        etype: "Quest".into(),
+                        id: name.to_string().into(),
+                    })?
+                    .get(variant)
+                    .ok_or_else(|| error::EntityVariantNotFound {
+                        etype: "Quest".into(),
+                        id: name.to_string().into(),
+                        variant: variant
+                            .clone()
+                            .map_or_else(|| "None".into(), |v| v.to_string().into()),
+                    })?
+                    .1,
+            )
+        })
+    }
+}
 
 impl HasNameVariant for DialogueRaw {
     type Name = Identifier;
@@ -493,6 +948,17 @@ impl HasNameVariant for RoomRaw {
         &self.variant
     }
 }
+impl HasNameVariant for QuestRaw {
+    type Name = Title;
+    type Id = QuestId;
+    type VariantId = QuestStageId;
+    fn name(&self) -> &Self::Name {
+        &self.name
+    }
+    fn variant(&self) -> &Option<Identifier> {
+        &self.variant
+    }
+}
 fn validate_section_types(ini: &Ini) -> Result<(), error::Application> {
     let allowed_set: HashSet<&'static str> = EntitySection::iter().map(|s| s.into()).collect();
     for section in ini.sections().flatten() {
@@ -509,6 +975,7 @@ fn validate_section_types(ini: &Ini) -> Result<(), error::Application> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::core::QuestStatus;
     use assert_matches::assert_matches;
     use asserting::prelude::*;
     use ini::Ini;
@@ -686,6 +1153,24 @@ mod test {
         assert_eq!(r.is_ok(), expected_ok);
     }
 
+    type ErrorCheck = Option<fn(&error::Application) -> bool>;
+
+    fn rejects(check: fn(&error::Application) -> bool) -> ErrorCheck {
+        Some(check)
+    }
+
+    fn assert_parse_error(
+        result: Result<World, error::Application>,
+        expected: ErrorCheck,
+        case: &str,
+    ) {
+        match (result.err(), expected) {
+            (None, None) => {}
+            (Some(e), Some(check)) => assert!(check(&e), "Wrong error for {case}: {e:?}"),
+            (e, _) => panic!("Unexpected result for {case}: {e:?}"),
+        }
+    }
+
     fn title_section() -> &'static str {
         r#"
 title = Some Title
@@ -998,6 +1483,915 @@ text=Response B
         assert_that!(world).is_ok();
     }
 
+    #[test]
+    fn parse_resolves_history_requirements() {
+        let ini = make_ini(&[
+            title_section(),
+            room_a(),
+            room_a_alt(),
+            room_b(),
+            item_a(),
+            item_b(),
+            action_give_item(),
+            character_a(),
+            dialogue_a(),
+            r#"
+            [Dialogue:dialogue_a|alt]
+            text=Dialogue a alt
+            requires=visited:RoomB,seen:dialogue_b,did:give_item_action,chose:response_b
+            "#,
+            dialogue_b(),
+            response_a(),
+            response_b(),
+        ]);
+
+        let world = parse(ini).unwrap();
+        assert_eq!(
+            world.dialogue(0.into())[1].requires,
+            vec![
+                Requirement::Visited(1.into()),
+                Requirement::Seen(1.into()),
+                Requirement::Did(0.into()),
+                Requirement::Chose(1.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_resolves_fragments() {
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            exits=east:RoomB
+            fragments=fragment_a
+            "#,
+            room_b(),
+            r#"
+            [Item:item_a]
+            description=Item a
+            fragments=fragment_a
+            "#,
+            r#"
+            [Fragment:fragment_a]
+            text=Something changed.
+            requires=visited:RoomB
+            "#,
+        ]);
+
+        let world = parse(ini).unwrap();
+        assert_eq!(world.room(0.into(), None).fragments, vec![0.into()]);
+        assert_eq!(world.item(0.into()).fragments, vec![0.into()]);
+        assert_eq!(
+            world.fragment(0.into()).requires,
+            vec![Requirement::Visited(1.into())]
+        );
+    }
+
+    #[test]
+    fn parse_resolves_quests() {
+        let ini = make_ini(&[
+            title_section(),
+            "[Room:RoomA]\ndescription=Room A description\nexits=east:RoomB\n",
+            room_b(),
+            r#"
+            [Quest:LostRing]
+            description=Find the ring.
+
+            [Quest:LostRing|found]
+            description=Return the ring.
+
+            [Action:start_search]
+            start_quest=LostRing
+            description=You promise to look for the ring.
+
+            [Action:find_ring]
+            advance_quest=LostRing->found
+            description=You found the ring!
+
+            [Action:return_ring]
+            complete_quest=LostRing
+            description=The old man thanks you.
+
+            [Action:lose_ring]
+            fail_quest=LostRing
+            description=The ring is gone for good.
+
+            [Fragment:searching]
+            text=You should look for that ring.
+            requires=quest_stage:LostRing,quest_status:LostRing|active
+            "#,
+        ]);
+
+        let world = parse(ini).unwrap();
+        assert_eq!(world.quest(0.into(), None).name, "Lost Ring");
+        assert_eq!(
+            world.quest(0.into(), Some(1.into())).description,
+            "Return the ring."
+        );
+        let quest_change = |id: usize| match world.action(id.into()) {
+            ActionEntity::ChangeQuest(c) => (c.stage, c.status),
+            other => panic!("Unexpected action {other:?}"),
+        };
+        assert_eq!(quest_change(0), (None, QuestStatus::Active));
+        assert_eq!(quest_change(1), (Some(1.into()), QuestStatus::Active));
+        assert_eq!(quest_change(2), (None, QuestStatus::Completed));
+        assert_eq!(quest_change(3), (None, QuestStatus::Failed));
+        assert_eq!(
+            world.fragment(0.into()).requires,
+            vec![
+                Requirement::QuestStage(0.into(), None),
+                Requirement::QuestStatus(0.into(), QuestStatus::Active),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_requires_stage_to_advance_quest() {
+        let ini = make_ini(&[
+            title_section(),
+            "[Room:RoomA]\ndescription=Room A description\nexits=east:RoomB\n",
+            room_b(),
+            "[Quest:LostRing]\ndescription=Find the ring.\n",
+            "[Action:find_ring]\nadvance_quest=LostRing\ndescription=You found the ring!\n",
+        ]);
+
+        assert_that!(parse(ini))
+            .is_err()
+            .extracting(|e| e.err().unwrap())
+            .satisfies(|e| {
+                matches!(e, error::PropertyNotFound { property, id, .. }
+                if property.as_ref() == "advance_quest:QuestName-><stage>"
+                    && id.as_ref() == "find_ring")
+            });
+    }
+
+    #[rstest]
+    #[case::answers("answers=Swordfish, open sesame", "open  Sesame", true)]
+    #[case::pattern("answer_pattern=^[0-9]{4}$", "1234", true)]
+    #[case::uppercase_pattern("answer_pattern=^[A-Z]{3}$", "Abc", true)]
+    #[case::wrong_answer("answers=swordfish", "trout", false)]
+    fn parse_resolves_answer_responses(
+        #[case] answer: &str,
+        #[case] input: &str,
+        #[case] accepted: bool,
+    ) {
+        let response = format!(
+            "[Response:response_a]\ntext=Say the password\n{answer}\nleads_to=dialogue_b\nfail_leads_to=dialogue_a\nfail_triggers=give_item_action\n"
+        );
+        let ini = make_ini(&[
+            title_section(),
+            room_a(),
+            room_b(),
+            item_a(),
+            action_give_item(),
+            character_a(),
+            dialogue_a(),
+            dialogue_b(),
+            &response,
+            response_b(),
+        ]);
+
+        let world = parse(ini).unwrap();
+        let response = world.response(0.into());
+        assert_eq!(response.leads_to, Some(1.into()));
+        assert_eq!(response.fail_leads_to, Some(0.into()));
+        assert_eq!(response.fail_triggers, Some(0.into()));
+        assert_eq!(
+            response.answer.as_ref().map(|a| a.accepts(input)),
+            Some(accepted)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_answer_pattern() {
+        let ini = make_ini(&[
+            title_section(),
+            room_a(),
+            room_b(),
+            character_a(),
+            dialogue_a(),
+            "[Response:response_a]\ntext=Say the password\nanswer_pattern=(unclosed\n",
+            response_b(),
+        ]);
+
+        assert_that!(parse(ini))
+            .is_err()
+            .extracting(|e| e.err().unwrap())
+            .satisfies(|e| matches!(e, error::InvalidPropertyValue { field, .. } if field.as_ref() == "answer_pattern"));
+    }
+
+    fn aliased_world(exit_aliases: &str, character_aliases: &str, action_aliases: &str) -> Ini {
+        let room = format!(
+            "[Room:RoomA]\ndescription=Room A description\nexits=east:RoomB\nexit_aliases={exit_aliases}\ncharacters=CharacterA\nactions=give_item_action\n"
+        );
+        let character = format!(
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\naliases={character_aliases}\n"
+        );
+        let action = format!(
+            "[Action:give_item_action]\ngive_item=item_a\ndescription=Give item a\naliases={action_aliases}\n"
+        );
+        make_ini(&[
+            title_section(),
+            &room,
+            room_b(),
+            &character,
+            dialogue_b(),
+            &action,
+            r#"
+            [Item:item_a]
+            description=Item a
+            aliases=thing
+            "#,
+        ])
+    }
+
+    #[test]
+    fn parse_resolves_aliases() {
+        let world = parse(aliased_world("east:door|way out", "stranger", "hand over")).unwrap();
+        let alias = |s: &str| s.parse::<Alias>().unwrap();
+        let room = world.room(0.into(), None);
+        assert_eq!(
+            room.exit_aliases(&"east".parse().unwrap())
+                .collect::<Vec<_>>(),
+            vec![alias("door"), alias("way out"), alias("e")]
+        );
+        assert_eq!(world.character(0.into()).aliases, vec![alias("stranger")]);
+        assert_eq!(
+            world.action(0.into()).common().aliases,
+            &[alias("hand over")]
+        );
+        assert_eq!(world.item(0.into()).aliases, vec![alias("thing")]);
+    }
+
+    #[rstest]
+    #[case::distinct("east:door", "stranger", "hand over", None)]
+    #[case::own_name("east:east|e", "character a", "give item action", None)]
+    #[case::exit_and_character(
+        "east:door",
+        "door",
+        "hand over",
+        rejects(|e| matches!(e, error::DuplicateAlias { room, alias, first, second }
+            if room.as_ref() == "Room A"
+                && alias.as_ref() == "door"
+                && first.as_ref() == "exit `east`"
+                && second.as_ref() == "character `Character A`"))
+    )]
+    #[case::compass_and_action(
+        "east:door",
+        "stranger",
+        "e",
+        rejects(|e| matches!(e, error::DuplicateAlias { room, alias, first, second }
+            if room.as_ref() == "Room A"
+                && alias.as_ref() == "e"
+                && first.as_ref() == "exit `east`"
+                && second.as_ref() == "action `give_item_action`"))
+    )]
+    #[case::alias_and_name(
+        "east:door",
+        "stranger",
+        "character a",
+        rejects(|e| matches!(e, error::DuplicateAlias { room, alias, first, second }
+            if room.as_ref() == "Room A"
+                && alias.as_ref() == "character a"
+                && first.as_ref() == "character `Character A`"
+                && second.as_ref() == "action `give_item_action`"))
+    )]
+    fn parse_checks_duplicate_aliases(
+        #[case] exit_aliases: &str,
+        #[case] character_aliases: &str,
+        #[case] action_aliases: &str,
+        #[case] expected: ErrorCheck,
+    ) {
+        let result = parse(aliased_world(
+            exit_aliases,
+            character_aliases,
+            action_aliases,
+        ));
+        assert_parse_error(
+            result,
+            expected,
+            &format!("`{exit_aliases}` `{character_aliases}` `{action_aliases}`"),
+        );
+    }
+
+    #[test]
+    fn parse_rejects_aliases_for_unknown_exit() {
+        let result = parse(aliased_world("west:door", "stranger", "hand over"));
+        assert_that!(result)
+            .is_err()
+            .extracting(|e| e.err().unwrap())
+            .satisfies(|e| {
+                matches!(e, error::InvalidPropertyValue { value, field, .. }
+                if value.as_ref() == "west" && field.as_ref() == "exit_aliases")
+            });
+    }
+
+    #[rstest]
+    #[case::timer("start_timer=burn_out->3", None)]
+    #[case::scheduled("give_item=item_a\nafter_turns=10\nevery=5", None)]
+    #[case::zero_turns(
+        "start_timer=burn_out->0",
+        rejects(|e| matches!(e, error::InvalidPropertyValue { value, field, .. }
+            if value.as_ref() == "0"
+                && field.as_ref() == "start_timer"))
+    )]
+    #[case::zero_every(
+        "give_item=item_a\nevery=0",
+        rejects(|e| matches!(e, error::InvalidPropertyValue { value, field, .. }
+            if value.as_ref() == "0"
+                && field.as_ref() == "every"))
+    )]
+    #[case::unknown_action(
+        "start_timer=snuff->3",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Action"
+                && id.as_ref() == "snuff"))
+    )]
+    fn parse_timed_actions(#[case] keys: &str, #[case] expected: ErrorCheck) {
+        let action = format!("[Action:light_candle]\ndescription=You light the candle.\n{keys}\n");
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            actions=light_candle
+            "#,
+            item_a(),
+            &action,
+            r#"
+            [Action:burn_out]
+            take_item=item_a
+            description=The candle burns out.
+            "#,
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{keys}`"));
+    }
+
+    #[rstest]
+    #[case::change_room("change_room=RoomA->alt")]
+    #[case::teleport("teleport_to=RoomB")]
+    #[case::give_item("give_item=item_a")]
+    #[case::take_item("take_item=item_a")]
+    #[case::replace_item("replace_item=item_a->item_b")]
+    #[case::sequence("sequence=burn_out")]
+    #[case::start_timer("start_timer=burn_out->3")]
+    #[case::start_quest("start_quest=LostRing")]
+    #[case::advance_quest("advance_quest=LostRing->found")]
+    #[case::complete_quest("complete_quest=LostRing")]
+    #[case::fail_quest("fail_quest=LostRing")]
+    #[case::change_var("change_var=coins+1")]
+    fn parse_accepts_duration_on_every_action(#[case] keys: &str) {
+        let action = format!(
+            "[Action:light_candle]\ndescription=You light the candle.\n{keys}\nduration=30\n"
+        );
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            exits=east:RoomB
+            actions=light_candle
+            "#,
+            room_a_alt(),
+            room_b(),
+            item_a(),
+            item_b(),
+            "[Quest:LostRing]\ndescription=Find the ring.\n",
+            "[Quest:LostRing|found]\ndescription=Return the ring.\n",
+            &action,
+            r#"
+            [Action:burn_out]
+            take_item=item_a
+            description=The candle burns out.
+            "#,
+        ]);
+        let world = parse(ini);
+        assert!(world.is_ok(), "Failed for `{keys}`: {world:?}");
+        assert_eq!(
+            world.unwrap().action(0.into()).common().duration,
+            Some("30".parse().unwrap()),
+            "Wrong duration for `{keys}`"
+        );
+    }
+
+    #[rstest]
+    #[case::join("join_party=CharacterA", "", None)]
+    #[case::leave("leave_party=CharacterA\nrequired=item_a", "", None)]
+    #[case::unknown_character(
+        "join_party=Stranger",
+        "",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Character"
+                && id.as_ref() == "Stranger"))
+    )]
+    #[case::in_party("join_party=CharacterA", "in_party:CharacterA", None)]
+    #[case::unknown_in_party(
+        "join_party=CharacterA",
+        "in_party:Stranger",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Character"
+                && id.as_ref() == "Stranger"))
+    )]
+    fn parse_party_actions(
+        #[case] keys: &str,
+        #[case] requires: &str,
+        #[case] expected: ErrorCheck,
+    ) {
+        let action = format!("[Action:recruit]\ndescription=Sam joins you.\n{keys}\n");
+        let dialogue = format!("[Dialogue:dialogue_b]\ntext=Dialogue b\nrequires={requires}\n");
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            actions=recruit
+            "#,
+            item_a(),
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\n",
+            &dialogue,
+            &action,
+        ]);
+        assert_parse_error(
+            parse(ini),
+            expected,
+            &format!("Failed for `{keys}` `{requires}`"),
+        );
+    }
+
+    #[rstest]
+    #[case::add("change_var=coins+10", None)]
+    #[case::several("change_var=coins-1, keys=0\nrequired=item_a", None)]
+    #[case::built_in(
+        "change_var=score+10",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Action"
+                && source.to_string() == "Cannot convert `score+10` to type VarChange"))
+    )]
+    #[case::no_amount(
+        "change_var=coins",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Action"
+                && source.to_string() == "Cannot convert `coins` to type VarChange"))
+    )]
+    #[case::unknown_required(
+        "change_var=coins+1\nrequired=item_z",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Item"
+                && id.as_ref() == "item_z"))
+    )]
+    fn parse_var_actions(#[case] keys: &str, #[case] expected: ErrorCheck) {
+        let action = format!("[Action:count_coins]\ndescription=You count your coins.\n{keys}\n");
+        let ini = make_ini(&[
+            title_section(),
+            "[Room:RoomA]\ndescription=Room A description\nactions=count_coins\n",
+            item_a(),
+            &action,
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{keys}`"));
+    }
+
+    #[rstest]
+    #[case::default("", None)]
+    #[case::she_her("pronouns=she/her", None)]
+    #[case::custom("pronouns=xe/xem/xyr/xyrs/xemself", None)]
+    #[case::unknown(
+        "pronouns=xe/xem",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Character"
+                && source.to_string() == "Cannot convert `xe/xem` to type Pronouns"))
+    )]
+    fn parse_character_pronouns(#[case] keys: &str, #[case] expected: ErrorCheck) {
+        let character = format!("[Character:CharacterA]\nstart_dialogue=dialogue_b\n{keys}\n");
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            "#,
+            &character,
+            dialogue_b(),
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{keys}`"));
+    }
+
+    #[test]
+    fn parse_art_blocks() {
+        let raw = [
+            title_section(),
+            "art=\"\"\"\n  ~ Title ~\n\"\"\"",
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            art="""
+             _
+            |_|
+            """
+            [Character:CharacterA]
+            start_dialogue=dialogue_b
+            art="""
+            (:
+            """
+            "#,
+            dialogue_b(),
+        ]
+        .join("\n");
+        let world = parse(preprocess_to_ini(&raw).unwrap()).unwrap();
+        assert_eq!(world.title().art().as_deref(), Some("  ~ Title ~"));
+        assert_eq!(
+            world.room(0.into(), None).art.as_deref(),
+            Some("             _\n            |_|")
+        );
+        assert_eq!(
+            world.character(0.into()).art.as_deref(),
+            Some("            (:")
+        );
+    }
+
+    #[rstest]
+    #[case::greeting("greet_dialogue=dialogue_b", None)]
+    #[case::conditional("greet_dialogue=dialogue_b\ngreet_requires=has_item:item_a", None)]
+    #[case::repeated("greet_dialogue=dialogue_b\ngreet_repeat=always", None)]
+    #[case::unknown_dialogue(
+        "greet_dialogue=dialogue_z",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Dialogue"
+                && id.as_ref() == "dialogue_z"))
+    )]
+    #[case::unknown_requirement(
+        "greet_dialogue=dialogue_b\ngreet_requires=has_item:item_z",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Item"
+                && id.as_ref() == "item_z"))
+    )]
+    #[case::invalid_repeat(
+        "greet_dialogue=dialogue_b\ngreet_repeat=never",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Character"
+                && source.to_string() == "Cannot convert `never` to type Repeat"))
+    )]
+    fn parse_character_greetings(#[case] keys: &str, #[case] expected: ErrorCheck) {
+        let character = format!("[Character:CharacterA]\nstart_dialogue=dialogue_b\n{keys}\n");
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            "#,
+            item_a(),
+            &character,
+            dialogue_b(),
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{keys}`"));
+    }
+
+    #[rstest]
+    #[case::speaker("speaker=CharacterA", None)]
+    #[case::unknown_speaker(
+        "speaker=Stranger",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Character"
+                && id.as_ref() == "Stranger"))
+    )]
+    fn parse_dialogue_speaker(#[case] keys: &str, #[case] expected: ErrorCheck) {
+        let dialogue = format!("[Dialogue:dialogue_a]\ntext=Dialogue a\n{keys}\n");
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            "#,
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\n",
+            dialogue_b(),
+            &dialogue,
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{keys}`"));
+    }
+
+    #[rstest]
+    #[case::action_gain("affinity=CharacterA+2", "", "", None)]
+    #[case::action_many("affinity=CharacterA+2,CharacterA-1", "", "", None)]
+    #[case::response_loss("", "affinity=CharacterA-3", "", None)]
+    #[case::unknown_character(
+        "affinity=Stranger+1",
+        "",
+        "",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Character"
+                && id.as_ref() == "Stranger"))
+    )]
+    #[case::missing_amount(
+        "affinity=CharacterA",
+        "",
+        "",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Action"
+                && source.to_string() == "Cannot convert `CharacterA` to type AffinityChange"))
+    )]
+    #[case::requirement("", "", "affinity:CharacterA>=5", None)]
+    #[case::unknown_requirement(
+        "",
+        "",
+        "affinity:Stranger>=5",
+        rejects(|e| matches!(e, error::EntityNotFound { etype, id }
+            if etype.as_ref() == "Character"
+                && id.as_ref() == "Stranger"))
+    )]
+    #[case::invalid_threshold(
+        "",
+        "",
+        "affinity:CharacterA=>5",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Dialogue"
+                && source.to_string() == "Cannot convert `=>5` to type Threshold"))
+    )]
+    fn parse_affinity(
+        #[case] action_keys: &str,
+        #[case] response_keys: &str,
+        #[case] requires: &str,
+        #[case] expected: ErrorCheck,
+    ) {
+        let action = format!(
+            "[Action:compliment]\ngive_item=item_a\ndescription=Sam smiles.\n{action_keys}\n"
+        );
+        let response = format!("[Response:thank_you]\ntext=Thank you!\n{response_keys}\n");
+        let dialogue = format!(
+            "[Dialogue:dialogue_b]\ntext=Dialogue b\nresponse=thank_you\nrequires={requires}\n"
+        );
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            actions=compliment
+            "#,
+            item_a(),
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\n",
+            &dialogue,
+            &response,
+            &action,
+        ]);
+        assert_parse_error(
+            parse(ini),
+            expected,
+            &format!("Failed for `{action_keys}` `{response_keys}` `{requires}`"),
+        );
+    }
+
+    #[rstest]
+    #[case::player_name("player_name:Ada", None)]
+    #[case::pronouns("pronouns:she/her", None)]
+    #[case::custom_pronouns("pronouns:xe/xem/xyr/xyrs/xemself", None)]
+    #[case::unknown_pronouns(
+        "pronouns:xe/xem",
+        rejects(|e| matches!(e, error::ConversionFailed { etype, source, .. }
+            if etype.as_ref() == "Dialogue"
+                && source.to_string() == "Cannot convert `xe/xem` to type Pronouns"))
+    )]
+    #[case::missing_name(
+        "player_name",
+        rejects(|e| matches!(e, error::PropertyNotFound { property, id, .. }
+            if property.as_ref() == "requires:player_name:<name>"
+                && id.as_ref() == "dialogue_b"))
+    )]
+    fn parse_player_requirements(#[case] requires: &str, #[case] expected: ErrorCheck) {
+        let dialogue = format!("[Dialogue:dialogue_b]\ntext=Dialogue b\nrequires={requires}\n");
+        let ini = make_ini(&[
+            title_section(),
+            r#"
+            [Room:RoomA]
+            description=Room A description
+            characters=CharacterA
+            "#,
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\n",
+            &dialogue,
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{requires}`"));
+    }
+
+    #[rstest]
+    #[case::no_placeholders("Room A description", None)]
+    #[case::player_name("Hello {player_name}", None)]
+    #[case::vars("{var:score} points after {var:turns} turns at {var:time}", None)]
+    #[case::item("You see {item:item_a} ({item:item_a.count})", None)]
+    #[case::character("{char:CharacterA} ({char:CharacterA.affinity})", None)]
+    #[case::escaped("Curly {{braces}}", None)]
+    #[case::custom_var("You have {var:coins} coins", None)]
+    #[case::unknown_var(
+        "You have {var:gold} gold",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "var:gold"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::unknown_item(
+        "You see {item:item_z}",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "item:item_z"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::unknown_character(
+        "{char:Stranger} waves",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "char:Stranger"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::unknown_kind(
+        "{weather}",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "weather"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::pronouns(
+        "{CharacterA.They} {CharacterA.are} here with {char:CharacterA.their} dog",
+        None
+    )]
+    #[case::unknown_pronoun_form(
+        "{CharacterA.xe}",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "CharacterA.xe"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::markup("A <b>heavy</b> door with <highlight>{item:item_a}</highlight>", None)]
+    #[case::unclosed_markup(
+        "A <i>heavy door",
+        rejects(|e| matches!(e, error::MalformedMarkup { problem, etype, id, field }
+            if problem.as_ref() == "tag `<i>` is never closed"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::unknown_markup(
+        "A <blink>heavy</blink> door",
+        rejects(|e| matches!(e, error::MalformedMarkup { problem, etype, id, field }
+            if problem.as_ref() == "unknown tag `<blink>`"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[case::pronouns_of_stranger(
+        "{Stranger.they}",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "Stranger.they"
+                && etype.as_ref() == "Room"
+                && id.as_ref() == "Room A"
+                && field.as_ref() == "description"))
+    )]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn parse_checks_templates(#[case] description: &str, #[case] expected: ErrorCheck) {
+        let room = format!(
+            "[Room:RoomA]\ndescription={description}\ncharacters=CharacterA\nactions=count_coins\n"
+        );
+        let ini = make_ini(&[
+            title_section(),
+            &room,
+            item_a(),
+            "[Action:count_coins]\ndescription=You count your coins.\nchange_var=coins+1\n",
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\n",
+            dialogue_b(),
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{description}`"));
+    }
+
+    #[rstest]
+    #[case::vars(
+        "{var:room} | Turn {var:turns} | Score {var:score} | {var:items} items",
+        None
+    )]
+    #[case::styled("<b>{var:room}</b> <highlight>{var:time}</highlight>", None)]
+    #[case::custom_var("Coins: {var:coins}", None)]
+    #[case::unknown_var(
+        "Gold: {var:gold}",
+        rejects(|e| matches!(e, error::UnknownPlaceholder { placeholder, etype, id, field }
+            if placeholder.as_ref() == "var:gold"
+                && etype.as_ref() == "Root"
+                && id.as_ref() == ""
+                && field.as_ref() == "status_bar"))
+    )]
+    #[case::unclosed_markup(
+        "<status>{var:room}",
+        rejects(|e| matches!(e, error::MalformedMarkup { problem, etype, id, field }
+            if problem.as_ref() == "tag `<status>` is never closed"
+                && etype.as_ref() == "Root"
+                && id.as_ref() == ""
+                && field.as_ref() == "status_bar"))
+    )]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn parse_checks_status_bar(#[case] status_bar: &str, #[case] expected: ErrorCheck) {
+        let status_bar = format!("status_bar={status_bar}\n");
+        let ini = make_ini(&[
+            title_section(),
+            &status_bar,
+            "[Room:RoomA]\ndescription=Room A description\nactions=count_coins\n",
+            "[Action:count_coins]\ndescription=You count your coins.\nchange_var=coins+1\n",
+        ]);
+        assert_parse_error(parse(ini), expected, &format!("`{status_bar}`"));
+    }
+
+    #[rstest]
+    #[case::tie_without_priority("has_item:item_b,visited:RoomB", "", false)]
+    #[case::tie_broken_by_priority("has_item:item_b,visited:RoomB", "priority=1", true)]
+    #[case::exclusive_requirements("does_not_have:item_a,visited:RoomB", "", true)]
+    fn parse_checks_ambiguous_dialogue_variants(
+        #[case] requires: &str,
+        #[case] priority: &str,
+        #[case] is_ok: bool,
+    ) {
+        let other = format!(
+            "[Dialogue:dialogue_a|other]\ntext=Dialogue a other\nrequires={requires}\n{priority}\n"
+        );
+        let ini = make_ini(&[
+            title_section(),
+            room_a(),
+            room_a_alt(),
+            room_b(),
+            item_a(),
+            item_b(),
+            action_give_item(),
+            character_a(),
+            dialogue_a(),
+            dialogue_a_alt(),
+            &other,
+            dialogue_b(),
+            response_a(),
+            response_b(),
+        ]);
+
+        let result = parse(ini);
+        if is_ok {
+            assert_that!(result).is_ok();
+        } else {
+            assert_that!(result)
+                .is_err()
+                .extracting(|e| e.err().unwrap())
+                .satisfies(|e| {
+                    assert_matches!(e, error::AmbiguousVariants { id, first, second, .. }
+                        if *id == "dialogue_a".into()
+                            && *first == "dialogue_a:alt".into()
+                            && *second == "dialogue_a:other".into());
+                    true
+                });
+        }
+    }
+
+    #[rstest]
+    #[case::tie_without_priority("time:night", "", false)]
+    #[case::tie_broken_by_priority("time:night", "priority=1", true)]
+    #[case::more_requirements("time:night,has_item:item_a", "", true)]
+    #[case::exclusive_requirements("time:morning", "", true)]
+    fn parse_checks_ambiguous_room_variants(
+        #[case] requires: &str,
+        #[case] priority: &str,
+        #[case] is_ok: bool,
+    ) {
+        let other = format!(
+            "[Room:RoomA|other]\ndescription=Room A other\nrequires={requires}\n{priority}\n"
+        );
+        let ini = make_ini(&[
+            title_section(),
+            room_a(),
+            "[Room:RoomA|dark]\ndescription=Room A dark\nrequires=time:night\n",
+            &other,
+            room_b(),
+            item_a(),
+            "[Character:CharacterA]\nstart_dialogue=dialogue_b\n",
+            dialogue_b(),
+        ]);
+
+        let result = parse(ini);
+        if is_ok {
+            assert_that!(result).is_ok();
+        } else {
+            assert_that!(result)
+                .is_err()
+                .extracting(|e| e.err().unwrap())
+                .satisfies(|e| {
+                    assert_matches!(e, error::AmbiguousVariants { etype, id, first, second }
+                        if *etype == "Room".into()
+                            && *id == "Room A".into()
+                            && *first == "Room A:dark".into()
+                            && *second == "Room A:other".into());
+                    true
+                });
+        }
+    }
+
     #[rstest]
     #[case::missing_default_dialogue(
     vec![
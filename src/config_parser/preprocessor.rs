@@ -7,6 +7,10 @@ use crate::error;
 
 const STR_DELIMITER: &str = r#"""""#;
 const ESCAPED_NEWLINE: &str = "\\n";
+const LITERAL_KEYS: [&str; 1] = ["art"];
+const ART_FILE_KEY: &str = "art_file";
+// Starts literal blocks so the ini parser doesn't trim their leading spaces.
+const LITERAL_MARKER: char = '\u{E000}';
 #[allow(clippy::expect_used)]
 static INVALID_DELIMITER_RX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#""{2,}"#).expect("Valid Rx"));
@@ -29,22 +33,26 @@ pub fn preprocess(input: &str) -> Result<String, error::Application> {
     let mut current_line = String::new();
     for line in input.lines() {
         let trimmed = line.trim();
-        if !in_block {
-            if let Some(pos) = trimmed.find('=') {
-                let key = trimmed[..pos].trim();
-                let value = trimmed[pos + 1..].trim();
-
-                if value == STR_DELIMITER {
-                    in_block = true;
-                    current_key = key.to_string();
-                    current_line.clear();
-                    continue;
-                }
+        if !in_block && let Some(pos) = trimmed.find('=') {
+            let key = trimmed[..pos].trim();
+            let value = trimmed[pos + 1..].trim();
+
+            if value == STR_DELIMITER {
+                in_block = true;
+                current_key = key.to_string();
+                current_line.clear();
+                continue;
             }
         }
         if in_block {
             if trimmed == STR_DELIMITER {
-                let escaped = current_line.trim_end().replace('\n', ESCAPED_NEWLINE);
+                let text = current_line.trim_end();
+                let escaped = if LITERAL_KEYS.contains(&current_key.as_str()) {
+                    format!("{LITERAL_MARKER}{}", text.replace('\\', "\\\\"))
+                        .replace('\n', ESCAPED_NEWLINE)
+                } else {
+                    text.replace('\n', ESCAPED_NEWLINE)
+                };
                 output.push_str(&format!("{current_key}={escaped}\n"));
                 in_block = false;
             } else {
@@ -62,9 +70,45 @@ pub fn preprocess(input: &str) -> Result<String, error::Application> {
 pub fn preprocess_to_ini_from_file(file: &Path) -> Result<Ini, error::Application> {
     let input =
         fs::read_to_string(file).map_err(|e| error::CouldNotLoadFile(e.to_string().into()))?;
+    let input = inline_art_files(&input, file.parent().unwrap_or_else(|| Path::new("")))?;
     preprocess_to_ini(input.as_str())
 }
 
+pub fn inline_art_files(input: &str, dir: &Path) -> Result<String, error::Application> {
+    let mut output = String::new();
+    let mut in_block = false;
+    for line in input.lines() {
+        let split = line.split_once('=');
+        if in_block {
+            in_block = line.trim() != STR_DELIMITER;
+        } else if let Some((_, value)) = split {
+            in_block = value.trim() == STR_DELIMITER;
+        }
+        if !in_block
+            && let Some((key, path)) = split
+            && key.trim() == ART_FILE_KEY
+        {
+            let path = path.trim();
+            let art = fs::read_to_string(dir.join(path))
+                .map_err(|e| error::CouldNotLoadFile(format!("{path}: {e}").into()))?;
+            output.push_str(&format!("art={STR_DELIMITER}\n"));
+            output.push_str(art.trim_end());
+            output.push_str(&format!("\n{STR_DELIMITER}\n"));
+        } else {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    Ok(output)
+}
+
+pub fn literal_text(value: &str) -> String {
+    value
+        .strip_prefix(LITERAL_MARKER)
+        .unwrap_or(value)
+        .to_string()
+}
+
 pub fn preprocess_to_ini(input: &str) -> Result<Ini, error::Application> {
     let preprocessed = preprocess(input)?;
     Ini::load_from_str(&preprocessed).map_err(|e| error::CouldNotLoadFile(e.to_string().into()))
@@ -135,6 +179,52 @@ responses=ask_name,ask_place
         assert_eq!(section.get("responses").unwrap(), "ask_name,ask_place");
     }
 
+    #[test]
+    fn art_block_keeps_spacing_and_backslashes() {
+        let raw = r#"
+[Room:Cellar]
+art="""
+   /\_/\
+  ( o.o )
+"""
+        "#;
+        let ini = preprocess_to_ini(raw).unwrap();
+        let section = ini.section(Some("Room:Cellar")).unwrap();
+        let art = literal_text(section.get("art").unwrap());
+
+        assert_eq!(art, "   /\\_/\\\n  ( o.o )");
+    }
+
+    #[test]
+    fn art_file_is_inlined() {
+        let dir = std::env::temp_dir().join(format!(
+            "text-adventure-art-file-is-inlined-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("cat.txt"), "  =^.^=\n").unwrap();
+        let raw = "[Room:Cellar]\nart_file = cat.txt\n";
+        let inlined = inline_art_files(raw, &dir);
+        let missing = inline_art_files("art_file=missing.txt", &dir);
+        fs::remove_dir_all(&dir).unwrap();
+        let ini = preprocess_to_ini(&inlined.unwrap()).unwrap();
+        let section = ini.section(Some("Room:Cellar")).unwrap();
+
+        assert_eq!(literal_text(section.get("art").unwrap()), "  =^.^=");
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn art_file_inside_block_is_kept() {
+        let raw = "[Room:Cellar]\ndescription=\"\"\"\nart_file=cat.txt\n\"\"\"\n";
+        let inlined = inline_art_files(raw, Path::new("no-such-dir")).unwrap();
+        let ini = preprocess_to_ini(&inlined).unwrap();
+        let section = ini.section(Some("Room:Cellar")).unwrap();
+
+        assert_eq!(section.get("description"), Some("art_file=cat.txt"));
+        assert_eq!(section.get("art"), None);
+    }
+
     #[test]
     fn preprocess_to_ini_malfored_string() {
         let raw = r#"
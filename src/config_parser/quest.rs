@@ -0,0 +1,72 @@
+use ini::SectionIter;
+
+use crate::{
+    core::{QuestRaw, Title},
+    error,
+};
+
+use super::iter::{EntitySection, SectionRecordIter};
+
+pub fn parse_quests(ini_iter: SectionIter) -> Result<Vec<QuestRaw>, error::Application> {
+    let mut list = Vec::new();
+    for record in SectionRecordIter::new(ini_iter, EntitySection::Quest) {
+        let record = record?.into_record(&["description"], &[])?;
+        let description = record.require("description")?.to_string();
+        let name = record.parse_name::<Title>()?;
+        list.push(QuestRaw {
+            name,
+            variant: record.variant().clone(),
+            description,
+        });
+    }
+    Ok(list)
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use ini::Ini;
+
+    use crate::config_parser::test_utils::i;
+
+    use super::*;
+    use asserting::prelude::*;
+
+    const GOOD_DATA: &str = r"
+        [Quest:LostRing]
+        description=The old man lost his ring somewhere in the house.
+
+        [Quest:LostRing|found]
+        description=You found the ring. Bring it back to the old man.
+    ";
+
+    const BAD_DATA_MISSING_DESCRIPTION: &str = r"
+        [Quest:LostRing]
+    ";
+
+    #[test]
+    fn parse_quests_successfully() {
+        let ini = Ini::load_from_str(GOOD_DATA).unwrap();
+        let quests = parse_quests(ini.iter()).unwrap();
+        assert_that!(&quests)
+            .has_length(2)
+            .satisfies_with_message("has expected names", |q| {
+                q.iter().all(|q| q.name.to_string() == "Lost Ring")
+            })
+            .satisfies_with_message("has expected stages", |q| {
+                q[0].variant.is_none() && q[1].variant == Some(i("found"))
+            });
+    }
+
+    #[test]
+    fn parse_quests_missing_description() {
+        let ini = Ini::load_from_str(BAD_DATA_MISSING_DESCRIPTION).unwrap();
+        let quests = parse_quests(ini.iter());
+        assert_that!(quests)
+            .is_err()
+            .extracting(|e| e.err().unwrap().to_string())
+            .contains("Missing")
+            .contains("description");
+    }
+}
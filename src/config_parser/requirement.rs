@@ -1,13 +1,22 @@
 use crate::{
-    core::{Identifier, RequirementRaw},
+    core::{
+        Identifier, Pronouns, QuestStatus, RequirementRaw, Threshold, TimeOfDay, Title, TurnRange,
+    },
     error,
 };
 
 use super::iter::{IterRequireWith, ParseWith, Record};
 
 pub fn parse_requirements(record: &Record) -> Result<Vec<RequirementRaw>, error::Application> {
+    parse_requirements_from(record, "requires")
+}
+
+pub fn parse_requirements_from(
+    record: &Record,
+    key: &'static str,
+) -> Result<Vec<RequirementRaw>, error::Application> {
     record
-        .get_list("requires")
+        .get_list(key)
         .map(|s| parse_one_requirement(record, s))
         .collect()
 }
@@ -36,6 +45,77 @@ fn parse_one_requirement(
             let (room, variant) = record.parse_qualified_name(qualified_name)?;
             RequirementRaw::RoomVariant(room, variant)
         }
+        "visited" => {
+            let room = parts.require_next(record, "requires:visited:<room>")?;
+            let room: Title = room.parse_with(record, "requires:visited:<room>")?;
+            RequirementRaw::Visited(room)
+        }
+        "seen" => {
+            let dialogue = parts.require_next(record, "requires:seen:<dialogue_id>")?;
+            let dialogue: Identifier =
+                dialogue.parse_with(record, "requires:seen:<dialogue_id>")?;
+            RequirementRaw::Seen(dialogue)
+        }
+        "did" => {
+            let action = parts.require_next(record, "requires:did:<action_id>")?;
+            let action: Identifier = action.parse_with(record, "requires:did:<action_id>")?;
+            RequirementRaw::Did(action)
+        }
+        "chose" => {
+            let response = parts.require_next(record, "requires:chose:<response_id>")?;
+            let response: Identifier =
+                response.parse_with(record, "requires:chose:<response_id>")?;
+            RequirementRaw::Chose(response)
+        }
+        "quest_stage" => {
+            let qualified_name = parts.require_next(record, "requires:quest_stage:<quest>")?;
+            let (quest, stage) = record.parse_qualified_name(qualified_name)?;
+            RequirementRaw::QuestStage(quest, stage)
+        }
+        "quest_status" => {
+            let qualified_name = parts.require_next(record, "requires:quest_status:<quest>")?;
+            let mut parts = qualified_name.splitn(2, '|').map(str::trim);
+            let quest = parts.require_next(record, "requires:quest_status:<quest>")?;
+            let quest: Title = quest.parse_with(record, "requires:quest_status:<quest>")?;
+            let status = parts.require_next(record, "requires:quest_status:quest|<status>")?;
+            let status: QuestStatus =
+                status.parse_with(record, "requires:quest_status:quest|<status>")?;
+            RequirementRaw::QuestStatus(quest, status)
+        }
+        "turns" => {
+            let range = parts.require_next(record, "requires:turns:<range>")?;
+            let range: TurnRange = range.parse_with(record, "requires:turns:<range>")?;
+            RequirementRaw::Turns(range)
+        }
+        "in_party" => {
+            let character = parts.require_next(record, "requires:in_party:<character>")?;
+            let character: Title = character.parse_with(record, "requires:in_party:<character>")?;
+            RequirementRaw::InParty(character)
+        }
+        "affinity" => {
+            let condition =
+                parts.require_next(record, "requires:affinity:<character><threshold>")?;
+            let split = condition.find(['<', '>', '=']).unwrap_or(condition.len());
+            let (character, threshold) = condition.split_at(split);
+            let character: Title = character.parse_with(record, "requires:affinity:<character>")?;
+            let threshold: Threshold =
+                threshold.parse_with(record, "requires:affinity:character<threshold>")?;
+            RequirementRaw::Affinity(character, threshold)
+        }
+        "player_name" => {
+            let name = parts.require_next(record, "requires:player_name:<name>")?;
+            RequirementRaw::PlayerName(name.into())
+        }
+        "pronouns" => {
+            let pronouns = parts.require_next(record, "requires:pronouns:<pronouns>")?;
+            let pronouns: Pronouns = pronouns.parse_with(record, "requires:pronouns:<pronouns>")?;
+            RequirementRaw::PlayerPronouns(pronouns)
+        }
+        "time" => {
+            let time = parts.require_next(record, "requires:time:<time_of_day>")?;
+            let time: TimeOfDay = time.parse_with(record, "requires:time:<time_of_day>")?;
+            RequirementRaw::Time(time)
+        }
         _ => {
             return Err(error::InvalidPropertyValue {
                 etype: record.entity_type().into(),
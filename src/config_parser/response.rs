@@ -1,11 +1,12 @@
 use ini::SectionIter;
+use regex::RegexBuilder;
 
 use crate::{
     config_parser::{
         iter::{EntitySection, SectionRecordIter},
         requirement::parse_requirements,
     },
-    core::ResponseRaw,
+    core::{Answer, ResponseRaw},
     error,
 };
 
@@ -14,11 +15,47 @@ pub fn parse_responses<'a>(
 ) -> Result<Vec<ResponseRaw>, error::Application> {
     let mut list = Vec::new();
     for record in SectionRecordIter::new(ini_iter, EntitySection::Response) {
-        let record = record?.into_record(&["text"], &["leads_to", "triggers", "requires"])?;
+        let record = record?.into_record(
+            &["text"],
+            &[
+                "leads_to",
+                "triggers",
+                "requires",
+                "repeat",
+                "score",
+                "affinity",
+                "answers",
+                "answer_pattern",
+                "fail_leads_to",
+                "fail_triggers",
+            ],
+        )?;
         let text = record.require("text")?.to_string();
         let leads_to = record.get_parsed("leads_to")?;
         let triggers = record.get_parsed("triggers")?;
         let requires = parse_requirements(&record)?;
+        let repeat = record.get_parsed("repeat")?.unwrap_or_default();
+        let score = record.get_parsed("score")?.unwrap_or_default();
+        let affinity = record
+            .get_list_parsed("affinity")
+            .collect::<Result<Vec<_>, error::Application>>()?;
+        let answer_pattern = record
+            .get("answer_pattern")
+            .map(|pattern| {
+                RegexBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|_| error::InvalidPropertyValue {
+                        etype: "Response".into(),
+                        value: pattern.into(),
+                        field: "answer_pattern".into(),
+                    })
+            })
+            .transpose()?;
+        let answer = (record.get("answers").is_some() || answer_pattern.is_some())
+            .then(|| Answer::new(record.get_list("answers"), answer_pattern));
+        let fail_leads_to = record.get_parsed("fail_leads_to")?;
+        let fail_triggers = record.get_parsed("fail_triggers")?;
         let name = record.parse_name()?;
         list.push(ResponseRaw {
             name,
@@ -26,6 +63,12 @@ pub fn parse_responses<'a>(
             leads_to,
             triggers,
             requires,
+            repeat,
+            score,
+            affinity,
+            answer,
+            fail_leads_to,
+            fail_triggers,
         });
     }
     Ok(list)
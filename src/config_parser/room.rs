@@ -2,17 +2,39 @@ use indexmap::IndexMap;
 use ini::SectionIter;
 
 use crate::{
-    core::{Identifier, RoomRaw, Title},
+    core::{Alias, Identifier, Minutes, RoomRaw, Title},
     error,
 };
 
-use super::iter::{EntitySection, IterRequireWith, ParseWith, SectionRecordIter};
+use super::{
+    iter::{EntitySection, IterRequireWith, ParseWith, SectionRecordIter},
+    preprocessor::literal_text,
+    requirement::parse_requirements,
+};
 
 pub fn parse_rooms<'a>(ini_iter: SectionIter<'a>) -> Result<Vec<RoomRaw>, error::Application> {
     let mut list = Vec::new();
     for record in SectionRecordIter::new(ini_iter, EntitySection::Room) {
-        let record = record?.into_record(&["description"], &["characters", "exits", "actions"])?;
+        let record = record?.into_record(
+            &["description"],
+            &[
+                "first_description",
+                "short_description",
+                "art",
+                "characters",
+                "exits",
+                "exit_aliases",
+                "travel_time",
+                "actions",
+                "fragments",
+                "requires",
+                "priority",
+            ],
+        )?;
         let description = record.require("description")?.to_string();
+        let first_description = record.get("first_description").map(str::to_string);
+        let short_description = record.get("short_description").map(str::to_string);
+        let art = record.get("art").map(literal_text);
         let exits = record
             .get_list("exits")
             .map(|exit| {
@@ -28,20 +50,92 @@ pub fn parse_rooms<'a>(ini_iter: SectionIter<'a>) -> Result<Vec<RoomRaw>, error:
                 Ok((direction, room))
             })
             .collect::<Result<IndexMap<Identifier, Title>, error::Application>>()?;
+        let exit_aliases = record
+            .get_list("exit_aliases")
+            .map(|entry| {
+                let mut parts = entry.split(":");
+                let direction = parts
+                    .require_next(&record, "exit_aliases=<direction>")?
+                    .trim()
+                    .parse_with::<Identifier>(&record, "exit_aliases=<direction>")?;
+                if !exits.contains_key(&direction) {
+                    return Err(error::InvalidPropertyValue {
+                        etype: "Room".into(),
+                        value: direction.to_string().into(),
+                        field: "exit_aliases".into(),
+                    });
+                }
+                let aliases = parts
+                    .require_next(&record, "exit_aliases=direction:<aliases>")?
+                    .split('|')
+                    .map(|alias| {
+                        alias
+                            .trim()
+                            .parse_with::<Alias>(&record, "exit_aliases=direction:<aliases>")
+                    })
+                    .collect::<Result<Vec<_>, error::Application>>()?;
+                Ok((direction, aliases))
+            })
+            .collect::<Result<IndexMap<Identifier, Vec<Alias>>, error::Application>>()?;
+        let travel_times = record
+            .get_list("travel_time")
+            .map(|entry| {
+                let mut parts = entry.split(":");
+                let direction = parts
+                    .require_next(&record, "travel_time=<direction>")?
+                    .trim()
+                    .parse_with::<Identifier>(&record, "travel_time=<direction>")?;
+                if !exits.contains_key(&direction) {
+                    return Err(error::InvalidPropertyValue {
+                        etype: "Room".into(),
+                        value: direction.to_string().into(),
+                        field: "travel_time".into(),
+                    });
+                }
+                let minutes = parts
+                    .require_next(&record, "travel_time=direction:<minutes>")?
+                    .trim()
+                    .parse_with::<Minutes>(&record, "travel_time=direction:<minutes>")?;
+                Ok((direction, minutes))
+            })
+            .collect::<Result<IndexMap<Identifier, Minutes>, error::Application>>()?;
         let characters = record
             .get_list_parsed("characters")
             .collect::<Result<Vec<_>, error::Application>>()?;
         let actions = record
             .get_list_parsed("actions")
             .collect::<Result<Vec<Identifier>, _>>()?;
+        let fragments = record
+            .get_list_parsed("fragments")
+            .collect::<Result<Vec<Identifier>, _>>()?;
+        let requires = parse_requirements(&record)?;
+        let priority = record
+            .get("priority")
+            .map(|p| {
+                p.parse::<i32>().map_err(|_| error::InvalidPropertyValue {
+                    etype: record.entity_type().into(),
+                    value: p.into(),
+                    field: "priority".into(),
+                })
+            })
+            .transpose()?
+            .unwrap_or_default();
         let name = record.parse_name::<Title>()?;
         list.push(RoomRaw {
             name,
             variant: record.variant().clone(),
             description,
+            first_description,
+            short_description,
+            art,
             characters,
             exits,
+            exit_aliases,
+            travel_times,
             actions,
+            fragments,
+            requires,
+            priority,
         });
     }
     Ok(list)
@@ -0,0 +1,57 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    core::{
+        CharacterId, GameVar, Identifier, ItemId, Placeholder, Segment, Title, markup_spans,
+        template_segments,
+    },
+    error,
+};
+
+pub struct TemplateChecker<'a> {
+    pub items: &'a HashMap<Identifier, ItemId>,
+    pub characters: &'a HashMap<Title, CharacterId>,
+    pub vars: &'a HashSet<Identifier>,
+}
+
+impl TemplateChecker<'_> {
+    pub fn check(
+        &self,
+        etype: &str,
+        id: &str,
+        field: &str,
+        text: &str,
+    ) -> Result<(), error::Application> {
+        for segment in template_segments(text) {
+            let Segment::Placeholder(inner) = segment else {
+                continue;
+            };
+            let known = match inner.parse::<Placeholder>() {
+                Ok(Placeholder::Item { item, .. }) => self.items.contains_key(&item),
+                Ok(Placeholder::Character { character, .. }) => {
+                    self.characters.contains_key(&character)
+                }
+                Ok(Placeholder::Var(GameVar::Custom(var))) => self.vars.contains(&var),
+                Ok(
+                    Placeholder::PlayerName | Placeholder::PlayerPronoun(_) | Placeholder::Var(_),
+                ) => true,
+                Err(_) => false,
+            };
+            if !known {
+                return Err(error::UnknownPlaceholder {
+                    placeholder: inner.into(),
+                    etype: etype.into(),
+                    id: id.into(),
+                    field: field.into(),
+                });
+            }
+        }
+        markup_spans(text).map_err(|problem| error::MalformedMarkup {
+            problem: problem.to_string().into(),
+            etype: etype.into(),
+            id: id.into(),
+            field: field.into(),
+        })?;
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
 use ini::{Ini, SectionIter};
 
 use crate::{
-    config_parser::iter::Record,
-    core::{GameTitleRaw, Language, Theme},
+    config_parser::{iter::Record, preprocessor::literal_text},
+    core::{GameTitleRaw, Language, Theme, ThemeColor},
     error,
 };
 
@@ -15,20 +15,66 @@ pub fn parse_title(ini: &Ini) -> Result<GameTitleRaw, error::Application> {
     let record = Record::from_root(
         properties,
         &["title", "greeting", "credits", "start_room"],
-        &[],
+        &[
+            "show_exhausted",
+            "max_score",
+            "start_time",
+            "minutes_per_turn",
+            "player_name",
+            "player_pronouns",
+            "ask_player_name",
+            "ask_pronouns",
+            "art",
+            "log_length",
+            "status_bar",
+        ],
     )?;
     let title = record.require("title")?.to_string();
     let greeting = record.require("greeting")?.to_string();
     let credits = record.require("credits")?.to_string();
     let start_room = record.require_parsed("start_room")?;
+    let show_exhausted = parse_flag(&record, "show_exhausted")?;
+    let max_score = record.get_parsed("max_score")?;
+    let start_time = record.get_parsed("start_time")?;
+    let minutes_per_turn = record.get_parsed("minutes_per_turn")?;
+    let player_name = record.get("player_name").map(String::from);
+    let player_pronouns = record.get_parsed("player_pronouns")?;
+    let ask_player_name = parse_flag(&record, "ask_player_name")?;
+    let ask_pronouns = parse_flag(&record, "ask_pronouns")?;
+    let art = record.get("art").map(literal_text);
+    let log_length = record.get_parsed("log_length")?;
+    let status_bar = record.get("status_bar").map(String::from);
     Ok(GameTitleRaw {
         title,
         greeting,
         credits,
         start_room,
+        show_exhausted,
+        max_score,
+        start_time,
+        minutes_per_turn,
+        player_name,
+        player_pronouns,
+        ask_player_name,
+        ask_pronouns,
+        art,
+        log_length,
+        status_bar,
     })
 }
 
+fn parse_flag(record: &Record, key: &'static str) -> Result<bool, error::Application> {
+    match record.get(key) {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(value) => Err(error::InvalidPropertyValue {
+            etype: record.entity_type().into(),
+            value: value.into(),
+            field: key.into(),
+        }),
+    }
+}
+
 pub fn parse_theme<'a>(ini_iter: SectionIter<'a>) -> Result<Theme, error::Application> {
     let mut iter = SectionRecordIter::new(ini_iter, EntitySection::Theme);
     let record = if let Some(r) = iter.next() {
@@ -42,7 +88,7 @@ pub fn parse_theme<'a>(ini_iter: SectionIter<'a>) -> Result<Theme, error::Applic
                 "highlight_text",
                 "subdued",
             ],
-            &[],
+            &["status", "status_background", "text_speed"],
         )?
     } else {
         return Ok(Theme::default());
@@ -50,10 +96,15 @@ pub fn parse_theme<'a>(ini_iter: SectionIter<'a>) -> Result<Theme, error::Applic
     let title = record.require_parsed("title")?;
     let heading = record.require_parsed("heading")?;
     let background = record.require_parsed("background")?;
-    let text = record.require_parsed("text")?;
-    let highlight = record.require_parsed("highlight")?;
+    let text: ThemeColor = record.require_parsed("text")?;
+    let highlight: ThemeColor = record.require_parsed("highlight")?;
     let highlight_text = record.require_parsed("highlight_text")?;
     let subdued = record.require_parsed("subdued")?;
+    let status = record.get_parsed("status")?.unwrap_or_else(|| text.clone());
+    let status_background = record
+        .get_parsed("status_background")?
+        .unwrap_or_else(|| highlight.clone());
+    let text_speed = record.get_parsed("text_speed")?;
     Ok(Theme::builder()
         .title(title)
         .heading(heading)
@@ -62,6 +113,9 @@ pub fn parse_theme<'a>(ini_iter: SectionIter<'a>) -> Result<Theme, error::Applic
         .highlight(highlight)
         .highlight_text(highlight_text)
         .subdued(subdued)
+        .status(status)
+        .status_background(status_background)
+        .maybe_text_speed(text_speed)
         .build())
 }
 
@@ -90,7 +144,32 @@ pub fn parse_language<'a>(ini_iter: SectionIter<'a>) -> Result<Language, error::
                 "continue_game",
                 "press_q_to_quit",
             ],
-            &[],
+            &[
+                "verbose_descriptions",
+                "brief_descriptions",
+                "unknown_command",
+                "known_commands",
+                "enter_answer",
+                "view_journal",
+                "journal",
+                "quests_active",
+                "quests_completed",
+                "quests_failed",
+                "final_score",
+                "achievements_unlocked",
+                "current_time",
+                "back_response",
+                "view_history",
+                "player_name_prompt",
+                "pronouns_prompt",
+                "unknown_pronouns",
+                "message_log",
+                "press_l_for_log",
+                "item_gained",
+                "item_lost",
+                "press_esc_to_quit",
+                "press_ctrl_l_for_log",
+            ],
         )?
     } else {
         return Ok(Language::default());
@@ -114,6 +193,45 @@ pub fn parse_language<'a>(ini_iter: SectionIter<'a>) -> Result<Language, error::
     let action_failed = record.require("action_failed")?.into();
     let continue_game = record.require("continue_game")?.into();
     let press_q_to_quit = record.require("press_q_to_quit")?.into();
+    let defaults = Language::default();
+    let verbose_descriptions = lang_field(
+        &record,
+        "verbose_descriptions",
+        defaults.verbose_descriptions(),
+    );
+    let brief_descriptions =
+        lang_field(&record, "brief_descriptions", defaults.brief_descriptions());
+    let unknown_command = lang_field(&record, "unknown_command", defaults.unknown_command());
+    let known_commands = lang_field(&record, "known_commands", defaults.known_commands());
+    let enter_answer = lang_field(&record, "enter_answer", defaults.enter_answer());
+    let view_journal = lang_field(&record, "view_journal", defaults.view_journal());
+    let journal = lang_field(&record, "journal", defaults.journal());
+    let quests_active = lang_field(&record, "quests_active", defaults.quests_active());
+    let quests_completed = lang_field(&record, "quests_completed", defaults.quests_completed());
+    let quests_failed = lang_field(&record, "quests_failed", defaults.quests_failed());
+    let final_score = lang_field(&record, "final_score", defaults.final_score());
+    let achievements_unlocked = lang_field(
+        &record,
+        "achievements_unlocked",
+        defaults.achievements_unlocked(),
+    );
+    let current_time = lang_field(&record, "current_time", defaults.current_time());
+    let back_response = lang_field(&record, "back_response", defaults.back_response());
+    let view_history = lang_field(&record, "view_history", defaults.view_history());
+    let player_name_prompt =
+        lang_field(&record, "player_name_prompt", defaults.player_name_prompt());
+    let pronouns_prompt = lang_field(&record, "pronouns_prompt", defaults.pronouns_prompt());
+    let unknown_pronouns = lang_field(&record, "unknown_pronouns", defaults.unknown_pronouns());
+    let message_log = lang_field(&record, "message_log", defaults.message_log());
+    let press_l_for_log = lang_field(&record, "press_l_for_log", defaults.press_l_for_log());
+    let item_gained = lang_field(&record, "item_gained", defaults.item_gained());
+    let item_lost = lang_field(&record, "item_lost", defaults.item_lost());
+    let press_esc_to_quit = lang_field(&record, "press_esc_to_quit", defaults.press_esc_to_quit());
+    let press_ctrl_l_for_log = lang_field(
+        &record,
+        "press_ctrl_l_for_log",
+        defaults.press_ctrl_l_for_log(),
+    );
     Ok(Language::builder()
         .characters_found(characters_found)
         .exits_found(exits_found)
@@ -134,9 +252,37 @@ pub fn parse_language<'a>(ini_iter: SectionIter<'a>) -> Result<Language, error::
         .action_failed(action_failed)
         .continue_game(continue_game)
         .press_q_to_quit(press_q_to_quit)
+        .verbose_descriptions(verbose_descriptions)
+        .brief_descriptions(brief_descriptions)
+        .unknown_command(unknown_command)
+        .known_commands(known_commands)
+        .enter_answer(enter_answer)
+        .view_journal(view_journal)
+        .journal(journal)
+        .quests_active(quests_active)
+        .quests_completed(quests_completed)
+        .quests_failed(quests_failed)
+        .final_score(final_score)
+        .achievements_unlocked(achievements_unlocked)
+        .current_time(current_time)
+        .back_response(back_response)
+        .view_history(view_history)
+        .player_name_prompt(player_name_prompt)
+        .pronouns_prompt(pronouns_prompt)
+        .unknown_pronouns(unknown_pronouns)
+        .message_log(message_log)
+        .press_l_for_log(press_l_for_log)
+        .item_gained(item_gained)
+        .item_lost(item_lost)
+        .press_esc_to_quit(press_esc_to_quit)
+        .press_ctrl_l_for_log(press_ctrl_l_for_log)
         .build())
 }
 
+fn lang_field(record: &Record, key: &'static str, default: &str) -> String {
+    record.get(key).map_or_else(|| default.into(), Into::into)
+}
+
 // Allowed in tests
 #[allow(clippy::unwrap_used)]
 #[cfg(test)]
@@ -185,6 +331,8 @@ mod test {
         greeting = Welcome to the beach bro!
         credits = Special thanks to my mom!
         start_room = TheCar
+        max_score = 100
+        start_time = 18:30
     ";
     const BAD_DATA_NO_START: &str = r"
         title = The Beach Trip
@@ -208,6 +356,37 @@ mod test {
         assert_eq!(title.greeting, "Welcome to the beach bro!".to_string());
         assert_eq!(title.credits, "Special thanks to my mom!".to_string());
         assert_eq!(title.start_room, t("TheCar"));
+        assert_eq!(title.max_score, Some("100".parse().unwrap()));
+        assert_eq!(title.start_time, Some("18:30".parse().unwrap()));
+        assert_eq!(title.minutes_per_turn, None);
+    }
+
+    #[test]
+    fn log_length_setting() {
+        let data = format!("{GOOD_DATA}\nlog_length = 20\n");
+        let title = parse_title(&Ini::load_from_str(&data).unwrap()).unwrap();
+        assert_eq!(title.log_length, Some("20".parse().unwrap()));
+
+        let data = format!("{GOOD_DATA}\nlog_length = lots\n");
+        let result = parse_title(&Ini::load_from_str(&data).unwrap());
+        assert_that!(result)
+            .is_err()
+            .satisfies(|r| matches!(r, Err(error::ConversionFailed { .. })));
+    }
+
+    #[test]
+    fn profile_prompt_settings() {
+        let data = format!("{GOOD_DATA}\nask_player_name = true\nplayer_pronouns = she/her\n");
+        let title = parse_title(&Ini::load_from_str(&data).unwrap()).unwrap();
+        assert!(title.ask_player_name);
+        assert!(!title.ask_pronouns);
+        assert_eq!(title.player_pronouns, Some("she/her".parse().unwrap()));
+
+        let data = format!("{GOOD_DATA}\nask_pronouns = maybe\n");
+        let result = parse_title(&Ini::load_from_str(&data).unwrap());
+        assert_that!(result)
+            .is_err()
+            .satisfies(|r| matches!(r, Err(error::InvalidPropertyValue { .. })));
     }
 
     #[test]
@@ -246,6 +425,34 @@ mod test {
         assert_eq!(theme.title().b(), &0u8);
     }
 
+    #[test]
+    fn theme_status_colors() {
+        let ini = Ini::load_from_str(GOOD_THEME_DATA).unwrap();
+        let theme = parse_theme(ini.iter()).unwrap();
+        assert_eq!(theme.status(), theme.text());
+        assert_eq!(theme.status_background(), theme.highlight());
+
+        let data = format!("{GOOD_THEME_DATA}\nstatus_background = Blue\n");
+        let ini = Ini::load_from_str(&data).unwrap();
+        let theme = parse_theme(ini.iter()).unwrap();
+        assert_eq!(theme.status_background(), &"Blue".parse().unwrap());
+    }
+
+    #[test]
+    fn theme_text_speed() {
+        let ini = Ini::load_from_str(GOOD_THEME_DATA).unwrap();
+        assert_eq!(parse_theme(ini.iter()).unwrap().text_speed(), &None);
+
+        let data = format!("{GOOD_THEME_DATA}\ntext_speed = 40\n");
+        let ini = Ini::load_from_str(&data).unwrap();
+        let theme = parse_theme(ini.iter()).unwrap();
+        assert_eq!(theme.text_speed(), &Some("40".parse().unwrap()));
+
+        let data = format!("{GOOD_THEME_DATA}\ntext_speed = fast\n");
+        let ini = Ini::load_from_str(&data).unwrap();
+        assert!(parse_theme(ini.iter()).is_err());
+    }
+
     #[test]
     fn language_good_data() {
         let ini = Ini::load_from_str(GOOD_LANGUAGE_DATA).unwrap();
@@ -0,0 +1,18 @@
+use crate::define_id;
+
+use super::{Requirement, RequirementRaw, invariant::Title};
+
+define_id!(AchievementId);
+
+#[derive(Debug)]
+pub struct AchievementRaw {
+    pub name: Title,
+    pub description: String,
+    pub requires: Vec<RequirementRaw>,
+}
+#[derive(Debug, PartialEq, Eq)]
+pub struct AchievementEntity {
+    pub name: String,
+    pub description: String,
+    pub requires: Vec<Requirement>,
+}
@@ -1,15 +1,38 @@
 use crate::{define_id, define_id_and_proxy};
 
 use super::{
-    IntoProxy, ItemId, RoomId, RoomVariantId, Title,
+    CharacterId, IntoProxy, ItemId, QuestId, QuestStageId, RoomId, RoomVariantId, Title,
     database::{Lookup, Update},
-    invariant::Identifier,
+    invariant::{
+        AffinityChange, Alias, Identifier, Minutes, QuestStatus, Repeat, Score, Timing, VarChange,
+    },
 };
 
+/// Fields every kind of action accepts
+#[derive(Debug)]
+pub struct ActionCommonRaw {
+    pub repeat: Repeat,
+    pub aliases: Vec<Alias>,
+    pub score: Score,
+    pub affinity: Vec<AffinityChange>,
+    pub timing: Option<Timing>,
+    pub duration: Option<Minutes>,
+}
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub struct ActionCommon {
+    pub repeat: Repeat,
+    pub aliases: Vec<Alias>,
+    pub score: Score,
+    pub affinity: Vec<(CharacterId, i32)>,
+    pub timing: Option<Timing>,
+    pub duration: Option<Minutes>,
+}
+
 #[derive(Debug)]
 pub struct ChangeRoomRaw {
     pub name: Identifier,
     pub description: String,
+    pub common: ActionCommonRaw,
     pub required: Option<Identifier>,
     pub room: Title,
     pub variant: Option<Identifier>,
@@ -18,6 +41,7 @@ pub struct ChangeRoomRaw {
 pub struct ChangeRoom {
     pub name: String,
     pub description: String,
+    pub common: ActionCommon,
     pub required: Option<ItemId>,
     pub room: RoomId,
     pub variant: Option<RoomVariantId>,
@@ -27,6 +51,7 @@ pub struct ChangeRoom {
 pub struct ReplaceItemRaw {
     pub name: Identifier,
     pub description: String,
+    pub common: ActionCommonRaw,
     pub original: Identifier,
     pub replacement: Identifier,
 }
@@ -34,6 +59,7 @@ pub struct ReplaceItemRaw {
 pub struct ReplaceItem {
     pub name: String,
     pub description: String,
+    pub common: ActionCommon,
     pub original: ItemId,
     pub replacement: ItemId,
 }
@@ -42,6 +68,7 @@ pub struct ReplaceItem {
 pub struct GiveItemRaw {
     pub name: Identifier,
     pub description: String,
+    pub common: ActionCommonRaw,
     pub required: Option<Identifier>,
     pub items: Vec<Identifier>,
 }
@@ -49,6 +76,7 @@ pub struct GiveItemRaw {
 pub struct GiveItem {
     pub name: String,
     pub description: String,
+    pub common: ActionCommon,
     pub required: Option<ItemId>,
     pub items: Vec<ItemId>,
 }
@@ -57,12 +85,14 @@ pub struct GiveItem {
 pub struct TakeItemRaw {
     pub name: Identifier,
     pub description: String,
+    pub common: ActionCommonRaw,
     pub items: Vec<Identifier>,
 }
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct TakeItem {
     pub name: String,
     pub description: String,
+    pub common: ActionCommon,
     pub items: Vec<ItemId>,
 }
 
@@ -70,6 +100,7 @@ pub struct TakeItem {
 pub struct TeleportRaw {
     pub name: Identifier,
     pub description: String,
+    pub common: ActionCommonRaw,
     pub required: Option<Identifier>,
     pub room: Title,
 }
@@ -77,6 +108,7 @@ pub struct TeleportRaw {
 pub struct Teleport {
     pub name: String,
     pub description: String,
+    pub common: ActionCommon,
     pub required: Option<ItemId>,
     pub room: RoomId,
 }
@@ -85,6 +117,7 @@ pub struct Teleport {
 pub struct SequenceRaw {
     pub name: Identifier,
     pub description: String,
+    pub common: ActionCommonRaw,
     pub required: Option<Identifier>,
     pub actions: Vec<Identifier>,
 }
@@ -92,10 +125,87 @@ pub struct SequenceRaw {
 pub struct Sequence {
     pub name: String,
     pub description: String,
+    pub common: ActionCommon,
     pub required: Option<ItemId>,
     pub actions: Vec<ActionId>,
 }
 
+#[derive(Debug)]
+pub struct ChangeQuestRaw {
+    pub name: Identifier,
+    pub description: String,
+    pub common: ActionCommonRaw,
+    pub required: Option<Identifier>,
+    pub quest: Title,
+    pub stage: Option<Identifier>,
+    pub status: QuestStatus,
+}
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChangeQuest {
+    pub name: String,
+    pub description: String,
+    pub common: ActionCommon,
+    pub required: Option<ItemId>,
+    pub quest: QuestId,
+    pub stage: Option<QuestStageId>,
+    pub status: QuestStatus,
+}
+
+#[derive(Debug)]
+pub struct StartTimerRaw {
+    pub name: Identifier,
+    pub description: String,
+    pub common: ActionCommonRaw,
+    pub required: Option<Identifier>,
+    pub action: Identifier,
+    pub turns: usize,
+}
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct StartTimer {
+    pub name: String,
+    pub description: String,
+    pub common: ActionCommon,
+    pub required: Option<ItemId>,
+    pub action: ActionId,
+    pub turns: usize,
+}
+
+#[derive(Debug)]
+pub struct ChangePartyRaw {
+    pub name: Identifier,
+    pub description: String,
+    pub common: ActionCommonRaw,
+    pub required: Option<Identifier>,
+    pub character: Title,
+    pub joins: bool,
+}
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChangeParty {
+    pub name: String,
+    pub description: String,
+    pub common: ActionCommon,
+    pub required: Option<ItemId>,
+    pub character: CharacterId,
+    pub joins: bool,
+}
+
+#[derive(Debug)]
+pub struct ChangeVarRaw {
+    pub name: Identifier,
+    pub description: String,
+    pub common: ActionCommonRaw,
+    pub required: Option<Identifier>,
+    pub changes: Vec<VarChange>,
+}
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChangeVar {
+    pub name: String,
+    pub description: String,
+    pub common: ActionCommon,
+    pub required: Option<ItemId>,
+    pub changes: Vec<VarChange>,
+}
+
 define_id_and_proxy!(ActionId, Action);
 
 #[derive(Debug)]
@@ -106,6 +216,10 @@ pub enum ActionRaw {
     TakeItem(TakeItemRaw),
     Teleport(TeleportRaw),
     Sequence(SequenceRaw),
+    ChangeQuest(ChangeQuestRaw),
+    StartTimer(StartTimerRaw),
+    ChangeParty(ChangePartyRaw),
+    ChangeVar(ChangeVarRaw),
 }
 impl ActionRaw {
     pub const fn name(&self) -> &Identifier {
@@ -116,6 +230,24 @@ impl ActionRaw {
             Self::TakeItem(take_item) => &take_item.name,
             Self::Teleport(teleport) => &teleport.name,
             Self::Sequence(chain) => &chain.name,
+            Self::ChangeQuest(change_quest) => &change_quest.name,
+            Self::StartTimer(start_timer) => &start_timer.name,
+            Self::ChangeParty(change_party) => &change_party.name,
+            Self::ChangeVar(change_var) => &change_var.name,
+        }
+    }
+    pub fn description(&self) -> &str {
+        match self {
+            Self::ChangeRoom(change_room) => &change_room.description,
+            Self::GiveItem(give_item) => &give_item.description,
+            Self::ReplaceItem(replace_item) => &replace_item.description,
+            Self::TakeItem(take_item) => &take_item.description,
+            Self::Teleport(teleport) => &teleport.description,
+            Self::Sequence(chain) => &chain.description,
+            Self::ChangeQuest(change_quest) => &change_quest.description,
+            Self::StartTimer(start_timer) => &start_timer.description,
+            Self::ChangeParty(change_party) => &change_party.description,
+            Self::ChangeVar(change_var) => &change_var.description,
         }
     }
 }
@@ -127,6 +259,54 @@ pub enum ActionEntity {
     TakeItem(TakeItem),
     Teleport(Teleport),
     Sequence(Sequence),
+    ChangeQuest(ChangeQuest),
+    StartTimer(StartTimer),
+    ChangeParty(ChangeParty),
+    ChangeVar(ChangeVar),
+}
+impl ActionEntity {
+    pub const fn common(&self) -> &ActionCommon {
+        match self {
+            Self::ChangeRoom(change_room) => &change_room.common,
+            Self::GiveItem(give_item) => &give_item.common,
+            Self::ReplaceItem(replace_item) => &replace_item.common,
+            Self::TakeItem(take_item) => &take_item.common,
+            Self::Teleport(teleport) => &teleport.common,
+            Self::Sequence(chain) => &chain.common,
+            Self::ChangeQuest(change_quest) => &change_quest.common,
+            Self::StartTimer(start_timer) => &start_timer.common,
+            Self::ChangeParty(change_party) => &change_party.common,
+            Self::ChangeVar(change_var) => &change_var.common,
+        }
+    }
+    pub fn description(&self) -> &str {
+        match self {
+            Self::ChangeRoom(change_room) => &change_room.description,
+            Self::GiveItem(give_item) => &give_item.description,
+            Self::ReplaceItem(replace_item) => &replace_item.description,
+            Self::TakeItem(take_item) => &take_item.description,
+            Self::Teleport(teleport) => &teleport.description,
+            Self::Sequence(chain) => &chain.description,
+            Self::ChangeQuest(change_quest) => &change_quest.description,
+            Self::StartTimer(start_timer) => &start_timer.description,
+            Self::ChangeParty(change_party) => &change_party.description,
+            Self::ChangeVar(change_var) => &change_var.description,
+        }
+    }
+    pub fn name(&self) -> &str {
+        match self {
+            Self::ChangeRoom(change_room) => &change_room.name,
+            Self::GiveItem(give_item) => &give_item.name,
+            Self::ReplaceItem(replace_item) => &replace_item.name,
+            Self::TakeItem(take_item) => &take_item.name,
+            Self::Teleport(teleport) => &teleport.name,
+            Self::Sequence(chain) => &chain.name,
+            Self::ChangeQuest(change_quest) => &change_quest.name,
+            Self::StartTimer(start_timer) => &start_timer.name,
+            Self::ChangeParty(change_party) => &change_party.name,
+            Self::ChangeVar(change_var) => &change_var.name,
+        }
+    }
 }
 impl<'a, DB: Lookup> Action<'a, DB> {
     fn action(&self) -> &ActionEntity {
@@ -141,18 +321,17 @@ impl<'a, DB: Lookup> Action<'a, DB> {
             A::TakeItem(take_item) => take_item.name.to_string(),
             A::Teleport(teleport) => teleport.name.to_string(),
             A::Sequence(chain) => chain.name.to_string(),
+            A::ChangeQuest(change_quest) => change_quest.name.to_string(),
+            A::StartTimer(start_timer) => start_timer.name.to_string(),
+            A::ChangeParty(change_party) => change_party.name.to_string(),
+            A::ChangeVar(change_var) => change_var.name.to_string(),
         }
     }
     pub fn description(&self) -> String {
-        use ActionEntity as A;
-        match self.action() {
-            A::ChangeRoom(change_room) => change_room.description.to_string(),
-            A::GiveItem(give_item) => give_item.description.to_string(),
-            A::ReplaceItem(replace_item) => replace_item.description.to_string(),
-            A::TakeItem(take_item) => take_item.description.to_string(),
-            A::Teleport(teleport) => teleport.description.to_string(),
-            A::Sequence(chain) => chain.description.to_string(),
-        }
+        self.db.render(self.action().description())
+    }
+    pub fn aliases(&self) -> &[Alias] {
+        &self.action().common().aliases
     }
     pub fn do_it(id: ActionId, db: &mut impl Update) -> bool {
         db.do_action(id)
@@ -1,9 +1,13 @@
 use crate::{define_id, define_id_and_proxy};
 
 use super::{
-    Action, ActionId, IntoProxy, ItemId, RoomId, RoomVariantId,
-    database::Lookup,
-    invariant::{Identifier, Title},
+    Action, ActionId, FragmentId, IntoProxy, ItemId, QuestId, QuestStageId, RoomId, RoomVariantId,
+    database::{Lookup, Update},
+    fragment::with_fragments,
+    invariant::{
+        AffinityChange, Alias, Answer, Identifier, Pronouns, QuestStatus, Repeat, Score, Threshold,
+        TimeOfDay, Title, TurnRange,
+    },
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -11,12 +15,69 @@ pub enum Requirement {
     HasItem(ItemId),
     RoomVariant(RoomId, Option<RoomVariantId>),
     DoesNotHave(ItemId),
+    Visited(RoomId),
+    Seen(DialogueId),
+    Did(ActionId),
+    Chose(ResponseId),
+    QuestStage(QuestId, Option<QuestStageId>),
+    QuestStatus(QuestId, QuestStatus),
+    Turns(TurnRange),
+    Time(TimeOfDay),
+    InParty(CharacterId),
+    Affinity(CharacterId, Threshold),
+    PlayerName(String),
+    PlayerPronouns(Pronouns),
+}
+impl Requirement {
+    pub fn excludes(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::HasItem(a), Self::DoesNotHave(b)) | (Self::DoesNotHave(a), Self::HasItem(b)) => {
+                a == b
+            }
+            (Self::RoomVariant(a, a_variant), Self::RoomVariant(b, b_variant)) => {
+                a == b && a_variant != b_variant
+            }
+            (Self::QuestStage(a, a_stage), Self::QuestStage(b, b_stage)) => {
+                a == b && a_stage != b_stage
+            }
+            (Self::QuestStatus(a, a_status), Self::QuestStatus(b, b_status)) => {
+                a == b && a_status != b_status
+            }
+            (Self::Turns(a), Self::Turns(b)) => !a.overlaps(b),
+            (Self::Time(a), Self::Time(b)) => !a.overlaps(b),
+            (Self::Affinity(a, a_threshold), Self::Affinity(b, b_threshold)) => {
+                a == b && !a_threshold.overlaps(b_threshold)
+            }
+            (Self::PlayerName(a), Self::PlayerName(b)) => !a.eq_ignore_ascii_case(b),
+            (Self::PlayerPronouns(a), Self::PlayerPronouns(b)) => a != b,
+            _ => false,
+        }
+    }
+    /// Whether two variants, given as their requirements and priority, could both be chosen
+    pub fn are_ambiguous((a, a_priority): (&[Self], i32), (b, b_priority): (&[Self], i32)) -> bool {
+        !a.is_empty()
+            && a.len() == b.len()
+            && a_priority == b_priority
+            && !a.iter().any(|a| b.iter().any(|b| a.excludes(b)))
+    }
 }
 #[derive(Debug)]
 pub enum RequirementRaw {
     HasItem(Identifier),
     RoomVariant(Title, Option<Identifier>),
     DoesNotHave(Identifier),
+    Visited(Title),
+    Seen(Identifier),
+    Did(Identifier),
+    Chose(Identifier),
+    QuestStage(Title, Option<Identifier>),
+    QuestStatus(Title, QuestStatus),
+    Turns(TurnRange),
+    Time(TimeOfDay),
+    InParty(Title),
+    Affinity(Title, Threshold),
+    PlayerName(String),
+    PlayerPronouns(Pronouns),
 }
 
 define_id_and_proxy!(CharacterId, Character);
@@ -28,11 +89,23 @@ define_id_and_proxy!(ResponseId, Response);
 pub struct CharacterRaw {
     pub name: Title,
     pub start_dialogue: Identifier,
+    pub aliases: Vec<Alias>,
+    pub greet_dialogue: Option<Identifier>,
+    pub greet_requires: Vec<RequirementRaw>,
+    pub greet_repeat: Repeat,
+    pub pronouns: Pronouns,
+    pub art: Option<String>,
 }
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct CharacterEntity {
     pub name: String,
     pub start_dialogue: DialogueId,
+    pub aliases: Vec<Alias>,
+    pub greet_dialogue: Option<DialogueId>,
+    pub greet_requires: Vec<Requirement>,
+    pub greet_repeat: Repeat,
+    pub pronouns: Pronouns,
+    pub art: Option<String>,
 }
 
 #[derive(Debug)]
@@ -42,6 +115,9 @@ pub struct DialogueRaw {
     pub text: String,
     pub responses: Vec<Identifier>,
     pub requires: Vec<RequirementRaw>,
+    pub fragments: Vec<Identifier>,
+    pub priority: i32,
+    pub speaker: Option<Title>,
 }
 pub type DialogueEntity = Vec<DialogueVariantEntity>;
 #[derive(Debug, PartialEq, Eq)]
@@ -49,6 +125,18 @@ pub struct DialogueVariantEntity {
     pub text: String,
     pub responses: Vec<ResponseId>,
     pub requires: Vec<Requirement>,
+    pub fragments: Vec<FragmentId>,
+    pub priority: i32,
+    pub speaker: Option<CharacterId>,
+}
+
+impl DialogueVariantEntity {
+    pub fn is_ambiguous_with(&self, other: &Self) -> bool {
+        Requirement::are_ambiguous(
+            (&self.requires, self.priority),
+            (&other.requires, other.priority),
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -58,6 +146,12 @@ pub struct ResponseRaw {
     pub leads_to: Option<Identifier>,
     pub triggers: Option<Identifier>,
     pub requires: Vec<RequirementRaw>,
+    pub repeat: Repeat,
+    pub score: Score,
+    pub affinity: Vec<AffinityChange>,
+    pub answer: Option<Answer>,
+    pub fail_leads_to: Option<Identifier>,
+    pub fail_triggers: Option<Identifier>,
 }
 #[derive(Debug, PartialEq, Eq)]
 pub struct ResponseEntity {
@@ -65,6 +159,12 @@ pub struct ResponseEntity {
     pub leads_to: Option<DialogueId>,
     pub triggers: Option<ActionId>,
     pub requires: Vec<Requirement>,
+    pub repeat: Repeat,
+    pub score: Score,
+    pub affinity: Vec<(CharacterId, i32)>,
+    pub answer: Option<Answer>,
+    pub fail_leads_to: Option<DialogueId>,
+    pub fail_triggers: Option<ActionId>,
 }
 
 impl<'a, T: Lookup> Character<'a, T> {
@@ -74,6 +174,12 @@ impl<'a, T: Lookup> Character<'a, T> {
     pub fn name(&self) -> &str {
         self.character().name.as_str()
     }
+    pub fn aliases(&self) -> &[Alias] {
+        &self.character().aliases
+    }
+    pub fn art(&self) -> Option<&str> {
+        self.character().art.as_deref()
+    }
     pub fn start_dialogue(&self) -> Dialogue<'_, T> {
         self.character().start_dialogue.into_proxy(self.db)
     }
@@ -82,13 +188,28 @@ impl<'a, T: Lookup> Dialogue<'a, T> {
     fn dialogue(&self) -> &DialogueVariantEntity {
         self.db.lookup_dialogue(self.id)
     }
-    pub fn text(&self) -> &str {
-        self.dialogue().text.as_str()
+    pub fn text(&self) -> String {
+        let dialogue = self.dialogue();
+        with_fragments(self.db, &dialogue.text, &dialogue.fragments)
+    }
+    pub fn speaker(&self) -> Option<Character<'_, T>> {
+        self.dialogue().speaker.map(|id| id.into_proxy(self.db))
+    }
+    pub fn view(id: DialogueId, db: &mut impl Update) {
+        db.view_dialogue(id);
     }
     pub fn responses(&self) -> impl Iterator<Item = Response<'_, T>> {
         self.db
             .filter_responses(&self.dialogue().responses)
             .into_iter()
+            .filter(|r| !self.db.is_response_exhausted(*r))
+            .map(|r| r.into_proxy(self.db))
+    }
+    pub fn exhausted_responses(&self) -> impl Iterator<Item = Response<'_, T>> {
+        self.db
+            .filter_responses(&self.dialogue().responses)
+            .into_iter()
+            .filter(|r| self.db.is_response_exhausted(*r))
             .map(|r| r.into_proxy(self.db))
     }
 }
@@ -96,8 +217,8 @@ impl<'a, T: Lookup> Response<'a, T> {
     fn response(&self) -> &ResponseEntity {
         self.db.lookup_response(self.id)
     }
-    pub fn text(&self) -> &str {
-        self.response().text.as_str()
+    pub fn text(&self) -> String {
+        self.db.render(&self.response().text)
     }
     pub fn leads_to(&self) -> Option<Dialogue<'_, T>> {
         self.response().leads_to.map(|id| id.into_proxy(self.db))
@@ -105,4 +226,32 @@ impl<'a, T: Lookup> Response<'a, T> {
     pub fn trigger(&self) -> Option<Action<'_, T>> {
         self.response().triggers.map(|id| id.into_proxy(self.db))
     }
+    pub fn was_chosen(&self) -> bool {
+        self.db.is_response_chosen(self.id)
+    }
+    pub fn asks_answer(&self) -> bool {
+        self.response().answer.is_some()
+    }
+    pub fn accepts(&self, input: &str) -> bool {
+        self.response()
+            .answer
+            .as_ref()
+            .is_none_or(|answer| answer.accepts(input))
+    }
+    pub fn fail_leads_to(&self) -> Option<Dialogue<'_, T>> {
+        self.response()
+            .fail_leads_to
+            .map(|id| id.into_proxy(self.db))
+    }
+    pub fn fail_trigger(&self) -> Option<Action<'_, T>> {
+        self.response()
+            .fail_triggers
+            .map(|id| id.into_proxy(self.db))
+    }
+    pub fn choose(id: ResponseId, db: &mut impl Update) {
+        db.choose_response(id);
+    }
+    pub fn fail(id: ResponseId, db: &mut impl Update) {
+        db.fail_response(id);
+    }
 }
@@ -1,6 +1,7 @@
 use super::{
     ActionEntity, ActionId, CharacterEntity, CharacterId, DialogueId, DialogueVariantEntity,
-    ResponseEntity, ResponseId, RoomId, RoomVariantEntity,
+    FragmentEntity, FragmentId, QuestId, QuestStageEntity, QuestStatus, ResponseEntity, ResponseId,
+    RoomId, RoomVariantEntity,
 };
 
 pub trait Lookup {
@@ -9,12 +10,26 @@ pub trait Lookup {
     fn lookup_room(&self, id: RoomId) -> &RoomVariantEntity;
     fn lookup_dialogue(&self, id: DialogueId) -> &DialogueVariantEntity;
     fn lookup_response(&self, id: ResponseId) -> &ResponseEntity;
+    fn lookup_fragment(&self, id: FragmentId) -> &FragmentEntity;
+    fn lookup_quest(&self, id: QuestId) -> &QuestStageEntity;
     fn filter_responses(&self, unfiltered: &[ResponseId]) -> Vec<ResponseId>;
+    fn filter_fragments(&self, unfiltered: &[FragmentId]) -> Vec<FragmentId>;
+    fn is_action_exhausted(&self, id: ActionId) -> bool;
+    fn is_response_exhausted(&self, id: ResponseId) -> bool;
+    fn is_response_chosen(&self, id: ResponseId) -> bool;
+    fn room_visits(&self, id: RoomId) -> usize;
+    fn is_verbose(&self) -> bool;
+    fn quest_status(&self, id: QuestId) -> QuestStatus;
+    fn characters_in(&self, room: RoomId) -> Vec<CharacterId>;
+    fn render(&self, text: &str) -> String;
 }
 
 pub trait Update {
     fn enter_room(&mut self, id: RoomId);
     fn do_action(&mut self, id: ActionId) -> bool;
+    fn choose_response(&mut self, id: ResponseId);
+    fn fail_response(&mut self, id: ResponseId);
+    fn view_dialogue(&mut self, id: DialogueId);
 }
 
 pub trait Database: Lookup + Update {}
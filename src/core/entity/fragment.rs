@@ -0,0 +1,31 @@
+use crate::define_id;
+
+use super::{Requirement, RequirementRaw, database::Lookup, invariant::Identifier};
+
+define_id!(FragmentId);
+
+#[derive(Debug)]
+pub struct FragmentRaw {
+    pub name: Identifier,
+    pub text: String,
+    pub requires: Vec<RequirementRaw>,
+}
+#[derive(Debug, PartialEq, Eq)]
+pub struct FragmentEntity {
+    pub text: String,
+    pub requires: Vec<Requirement>,
+}
+
+pub fn with_fragments(db: &impl Lookup, text: &str, fragments: &[FragmentId]) -> String {
+    let joined =
+        db.filter_fragments(fragments)
+            .into_iter()
+            .fold(text.to_string(), |mut joined, id| {
+                if !joined.is_empty() {
+                    joined.push(' ');
+                }
+                joined.push_str(&db.lookup_fragment(id).text);
+                joined
+            });
+    db.render(&joined)
+}
@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use super::{IllegalConversion, Title};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffinityChange {
+    pub character: Title,
+    pub amount: i32,
+}
+
+impl FromStr for AffinityChange {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IllegalConversion {
+            value: s.into(),
+            dtype: "AffinityChange",
+        };
+        let split = s.rfind(['+', '-']).ok_or_else(error)?;
+        let (character, amount) = s.split_at(split);
+        Ok(Self {
+            character: character.trim().parse().map_err(|_| error())?,
+            amount: amount
+                .split_whitespace()
+                .collect::<String>()
+                .parse()
+                .map_err(|_| error())?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Threshold {
+    min: i32,
+    max: i32,
+}
+
+impl Threshold {
+    pub const fn contains(&self, value: i32) -> bool {
+        self.min <= value && value <= self.max
+    }
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.min.max(other.min) <= self.max.min(other.max)
+    }
+}
+
+impl FromStr for Threshold {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IllegalConversion {
+            value: s.into(),
+            dtype: "Threshold",
+        };
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !matches!(c, '<' | '>' | '='))
+            .ok_or_else(error)?;
+        let (operator, value) = s.split_at(split);
+        let value = value.trim().parse::<i32>().map_err(|_| error())?;
+        let (min, max) = match operator {
+            ">=" => (value, i32::MAX),
+            ">" => (value.checked_add(1).ok_or_else(error)?, i32::MAX),
+            "<=" => (i32::MIN, value),
+            "<" => (i32::MIN, value.checked_sub(1).ok_or_else(error)?),
+            "=" | "==" => (value, value),
+            _ => return Err(error()),
+        };
+        Ok(Self { min, max })
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::gain("OldMan+2", "Old Man", 2)]
+    #[case::loss("Sam - 1", "Sam", -1)]
+    #[case::large_loss("PeskyKid-10", "Pesky Kid", -10)]
+    fn valid_affinity_change(#[case] input: &str, #[case] name: &str, #[case] amount: i32) {
+        let change = input.parse::<AffinityChange>().unwrap();
+        assert_eq!(change.character.to_string(), name);
+        assert_eq!(change.amount, amount);
+    }
+
+    #[rstest]
+    #[case::no_amount("OldMan")]
+    #[case::no_name("+2")]
+    #[case::word_amount("OldMan+two")]
+    fn invalid_affinity_change(#[case] input: &str) {
+        assert!(input.parse::<AffinityChange>().is_err());
+    }
+
+    #[rstest]
+    #[case::at_least(">=5", 5, true)]
+    #[case::at_least_miss(">=5", 4, false)]
+    #[case::more_than(">5", 5, false)]
+    #[case::at_most("<=-2", -2, true)]
+    #[case::less_than("<0", 0, false)]
+    #[case::exactly("=3", 3, true)]
+    #[case::exactly_miss("==3", 4, false)]
+    fn threshold_contains(#[case] input: &str, #[case] value: i32, #[case] expected: bool) {
+        let threshold = input.parse::<Threshold>().unwrap();
+        assert_eq!(threshold.contains(value), expected, "Failed for `{input}`");
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::no_operator("5")]
+    #[case::no_value(">=")]
+    #[case::bad_operator("=>5")]
+    fn invalid_threshold(#[case] input: &str) {
+        assert!(input.parse::<Threshold>().is_err());
+    }
+
+    #[rstest]
+    #[case::disjoint(">=5", "<5", false)]
+    #[case::touching(">=5", "<=5", true)]
+    #[case::nested(">0", "=3", true)]
+    fn threshold_overlaps(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        let (a, b) = (a.parse::<Threshold>().unwrap(), b.parse().unwrap());
+        assert_eq!(a.overlaps(&b), expected);
+    }
+}
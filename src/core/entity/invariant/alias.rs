@@ -0,0 +1,88 @@
+use derive_more::{AsRef, Debug, Display};
+use std::{rc::Rc, str::FromStr};
+
+use super::{Identifier, IllegalConversion};
+
+const COMPASS: &[(&str, &str)] = &[
+    ("north", "n"),
+    ("south", "s"),
+    ("east", "e"),
+    ("west", "w"),
+    ("northeast", "ne"),
+    ("northwest", "nw"),
+    ("southeast", "se"),
+    ("southwest", "sw"),
+    ("up", "u"),
+    ("down", "d"),
+];
+
+#[derive(Debug, Display, AsRef, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Alias(Rc<String>);
+
+impl Alias {
+    pub fn compass(direction: &Identifier) -> Option<Self> {
+        let direction = direction.to_string().replace('_', "");
+        COMPASS
+            .iter()
+            .find(|(full, _)| *full == direction)
+            .map(|(_, short)| Self(Rc::new((*short).into())))
+    }
+}
+
+impl FromStr for Alias {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let alias = s
+            .to_lowercase()
+            .replace(['_', '-'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !alias.is_empty() && alias.chars().all(|c| c.is_alphanumeric() || c == ' ') {
+            Ok(Self(Rc::new(alias)))
+        } else {
+            Err(IllegalConversion {
+                value: s.into(),
+                dtype: "Alias",
+            })
+        }
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::word("Stairs", "stairs")]
+    #[case::spaces("  old   Man ", "old man")]
+    #[case::separators("rusty_key", "rusty key")]
+    fn valid_alias(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(input.parse::<Alias>().unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::blank("  ")]
+    #[case::punctuation("old man!")]
+    fn invalid_alias(#[case] input: &str) {
+        assert!(input.parse::<Alias>().is_err());
+    }
+
+    #[rstest]
+    #[case::north("north", Some("n"))]
+    #[case::south_east("south_east", Some("se"))]
+    #[case::up("up", Some("u"))]
+    #[case::other("downstairs", None)]
+    fn compass_abbreviation(#[case] direction: &str, #[case] expected: Option<&str>) {
+        let direction = direction.parse::<Identifier>().unwrap();
+        assert_eq!(
+            Alias::compass(&direction).map(|a| a.to_string()),
+            expected.map(String::from)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    answers: Vec<String>,
+    pattern: Option<Regex>,
+}
+
+impl Answer {
+    pub fn new<'a>(answers: impl IntoIterator<Item = &'a str>, pattern: Option<Regex>) -> Self {
+        Self {
+            answers: answers.into_iter().map(normalize).collect(),
+            pattern,
+        }
+    }
+    pub fn accepts(&self, input: &str) -> bool {
+        let input = normalize(input);
+        self.answers.contains(&input) || self.pattern.as_ref().is_some_and(|p| p.is_match(&input))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.answers == other.answers
+            && self.pattern.as_ref().map(Regex::as_str) == other.pattern.as_ref().map(Regex::as_str)
+    }
+}
+impl Eq for Answer {}
+
+fn normalize(input: &str) -> String {
+    input
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::exact("swordfish", true)]
+    #[case::case_and_spacing("  Open   SESAME ", true)]
+    #[case::pattern("1234", true)]
+    #[case::wrong("sesame", false)]
+    #[case::partial_pattern("12345", false)]
+    #[case::empty("", false)]
+    fn answer_accepts(#[case] input: &str, #[case] expected: bool) {
+        let answer = Answer::new(
+            ["swordfish", "open sesame"],
+            Some(Regex::new(r"^\d{4}$").unwrap()),
+        );
+        assert_eq!(answer.accepts(input), expected, "Failed for `{input}`");
+    }
+}
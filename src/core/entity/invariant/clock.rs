@@ -0,0 +1,172 @@
+use derive_more::Display;
+use std::str::FromStr;
+
+use super::IllegalConversion;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+const PERIODS: &[(&str, u32, u32)] = &[
+    ("morning", 6 * 60, 12 * 60),
+    ("afternoon", 12 * 60, 18 * 60),
+    ("evening", 18 * 60, 22 * 60),
+    ("night", 22 * 60, 6 * 60),
+];
+
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[display("{:02}:{:02}", _0 / 60, _0 % 60)]
+pub struct ClockTime(u32);
+
+impl ClockTime {
+    pub const fn after(&self, minutes: u32) -> Self {
+        Self((self.0 + minutes % MINUTES_PER_DAY) % MINUTES_PER_DAY)
+    }
+}
+
+impl FromStr for ClockTime {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hours, minutes) = s
+            .trim()
+            .split_once(':')
+            .and_then(|(h, m)| Some((h.parse::<u32>().ok()?, m.parse::<u32>().ok()?)))
+            .filter(|(h, m)| *h < 24 && *m < 60)
+            .ok_or_else(|| IllegalConversion {
+                value: s.into(),
+                dtype: "ClockTime",
+            })?;
+        Ok(Self(hours * 60 + minutes))
+    }
+}
+
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Minutes(u32);
+
+impl Minutes {
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl FromStr for Minutes {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<u32>()
+            .map(Self)
+            .map_err(|_| IllegalConversion {
+                value: s.into(),
+                dtype: "Minutes",
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeOfDay {
+    from: ClockTime,
+    to: ClockTime,
+}
+
+impl TimeOfDay {
+    pub const fn contains(&self, time: ClockTime) -> bool {
+        if self.from.0 < self.to.0 {
+            self.from.0 <= time.0 && time.0 < self.to.0
+        } else {
+            self.from.0 <= time.0 || time.0 < self.to.0
+        }
+    }
+    pub const fn overlaps(&self, other: &Self) -> bool {
+        self.contains(other.from) || other.contains(self.from)
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IllegalConversion {
+            value: s.into(),
+            dtype: "TimeOfDay",
+        };
+        let name = s.trim().to_lowercase();
+        let (from, to) = match PERIODS.iter().find(|(period, _, _)| *period == name) {
+            Some((_, from, to)) => (ClockTime(*from), ClockTime(*to)),
+            None => {
+                let (from, to) = name.split_once("..").ok_or_else(error)?;
+                (
+                    from.parse().map_err(|_| error())?,
+                    to.parse().map_err(|_| error())?,
+                )
+            }
+        };
+        if from == to {
+            return Err(error());
+        }
+        Ok(Self { from, to })
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::midnight("00:00", "00:00")]
+    #[case::padded("7:05", "07:05")]
+    #[case::evening("18:30", "18:30")]
+    fn valid_clock_time(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(input.parse::<ClockTime>().unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::hours("24:00")]
+    #[case::minutes("12:60")]
+    #[case::word("noon")]
+    fn invalid_clock_time(#[case] input: &str) {
+        assert!(input.parse::<ClockTime>().is_err());
+    }
+
+    #[test]
+    fn clock_time_wraps_past_midnight() {
+        let time = "23:30".parse::<ClockTime>().unwrap().after(45);
+        assert_eq!(time.to_string(), "00:15");
+    }
+
+    #[rstest]
+    #[case::morning("morning", "06:00", true)]
+    #[case::morning_end("morning", "12:00", false)]
+    #[case::night_late("Night", "23:59", true)]
+    #[case::night_early("night", "05:59", true)]
+    #[case::night_day("night", "13:00", false)]
+    #[case::range("09:00..17:00", "16:59", true)]
+    #[case::wrapped_range("20:00..02:00", "01:00", true)]
+    fn time_of_day_contains(#[case] input: &str, #[case] time: &str, #[case] expected: bool) {
+        let range = input.parse::<TimeOfDay>().unwrap();
+        let time = time.parse::<ClockTime>().unwrap();
+        assert_eq!(range.contains(time), expected, "Failed for `{input}`");
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown("dusk")]
+    #[case::same("08:00..08:00")]
+    #[case::open("08:00..")]
+    fn invalid_time_of_day(#[case] input: &str) {
+        assert!(input.parse::<TimeOfDay>().is_err());
+    }
+
+    #[rstest]
+    #[case::periods("evening", "night", false)]
+    #[case::wrapped("night", "05:00..07:00", true)]
+    #[case::nested("afternoon", "13:00..14:00", true)]
+    fn time_of_day_overlaps(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        let a = a.parse::<TimeOfDay>().unwrap();
+        let b = b.parse::<TimeOfDay>().unwrap();
+        assert_eq!(a.overlaps(&b), expected);
+        assert_eq!(b.overlaps(&a), expected);
+    }
+}
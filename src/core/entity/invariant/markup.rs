@@ -0,0 +1,210 @@
+use std::str::FromStr;
+
+use derive_more::Display;
+
+use super::IllegalConversion;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeSlot {
+    Title,
+    Heading,
+    Background,
+    Text,
+    Highlight,
+    HighlightText,
+    Subdued,
+    Status,
+    StatusBackground,
+}
+
+impl FromStr for ThemeSlot {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "title" => Ok(Self::Title),
+            "heading" => Ok(Self::Heading),
+            "background" => Ok(Self::Background),
+            "text" => Ok(Self::Text),
+            "highlight" => Ok(Self::Highlight),
+            "highlight_text" => Ok(Self::HighlightText),
+            "subdued" => Ok(Self::Subdued),
+            "status" => Ok(Self::Status),
+            "status_background" => Ok(Self::StatusBackground),
+            _ => Err(IllegalConversion {
+                value: s.into(),
+                dtype: "ThemeSlot",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextStyle {
+    Bold,
+    Italic,
+    Underline,
+    Color(ThemeSlot),
+}
+
+impl FromStr for TextStyle {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "b" | "bold" => Ok(Self::Bold),
+            "i" | "italic" => Ok(Self::Italic),
+            "u" | "underline" => Ok(Self::Underline),
+            _ => Ok(Self::Color(s.parse().map_err(|_| IllegalConversion {
+                value: s.into(),
+                dtype: "TextStyle",
+            })?)),
+        }
+    }
+}
+
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum MarkupError {
+    #[display("unknown tag `<{_0}>`")]
+    Unknown(String),
+    #[display("tag `<{_0}>` is never closed")]
+    Unclosed(String),
+    #[display("tag `</{_0}>` closes nothing")]
+    Unopened(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledSpan<'a> {
+    pub text: &'a str,
+    pub styles: Vec<TextStyle>,
+}
+
+// A tag is `<name>` or `</name>` where name is lowercase letters and underscores.
+fn tag_at(text: &str) -> Option<(bool, &str, usize)> {
+    let inner = text.strip_prefix('<')?;
+    let (closing, inner) = inner
+        .strip_prefix('/')
+        .map_or((false, inner), |inner| (true, inner));
+    let end = inner.find('>')?;
+    let name = &inner[..end];
+    let is_name = !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '_');
+    is_name.then(|| (closing, name, end + usize::from(closing) + 2))
+}
+
+pub fn markup_spans(text: &str) -> Result<Vec<StyledSpan<'_>>, MarkupError> {
+    let mut spans = Vec::new();
+    let mut open: Vec<(&str, TextStyle)> = Vec::new();
+    let styles = |open: &[(&str, TextStyle)]| open.iter().map(|(_, s)| *s).collect();
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        let (before, after) = rest.split_at(start);
+        if !before.is_empty() {
+            spans.push(StyledSpan {
+                text: before,
+                styles: styles(&open),
+            });
+        }
+        if let Some(after) = after.strip_prefix("<<") {
+            spans.push(StyledSpan {
+                text: "<",
+                styles: styles(&open),
+            });
+            rest = after;
+        } else if let Some((closing, name, len)) = tag_at(after) {
+            if closing {
+                match open.pop() {
+                    Some((opened, _)) if opened == name => {}
+                    _ => return Err(MarkupError::Unopened(name.into())),
+                }
+            } else {
+                let style = name
+                    .parse()
+                    .map_err(|_| MarkupError::Unknown(name.into()))?;
+                open.push((name, style));
+            }
+            rest = &after[len..];
+        } else {
+            spans.push(StyledSpan {
+                text: "<",
+                styles: styles(&open),
+            });
+            rest = &after[1..];
+        }
+    }
+    if let Some((name, _)) = open.pop() {
+        return Err(MarkupError::Unclosed(name.into()));
+    }
+    if !rest.is_empty() {
+        spans.push(StyledSpan {
+            text: rest,
+            styles: styles(&open),
+        });
+    }
+    Ok(spans)
+}
+
+pub fn strip_markup(text: &str) -> String {
+    markup_spans(text).map_or_else(
+        |_| text.to_string(),
+        |spans| spans.iter().map(|span| span.text).collect(),
+    )
+}
+
+/// Escape text so it is shown as typed rather than read as markup
+pub fn escape_markup(text: &str) -> String {
+    text.replace('<', "<<")
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn nested_tags_stack_styles() {
+        let spans = markup_spans("A <b>bold <highlight>coin</highlight></b>!").unwrap();
+        let expected = [
+            ("A ", vec![]),
+            ("bold ", vec![TextStyle::Bold]),
+            (
+                "coin",
+                vec![TextStyle::Bold, TextStyle::Color(ThemeSlot::Highlight)],
+            ),
+            ("!", vec![]),
+        ];
+        assert_eq!(spans.len(), expected.len());
+        for (span, (text, styles)) in spans.iter().zip(expected) {
+            assert_eq!(span.text, text);
+            assert_eq!(span.styles, styles);
+        }
+    }
+
+    #[rstest]
+    #[case::plain("Nothing to see", "Nothing to see")]
+    #[case::styled("<i>Quiet</i> <u>now</u>", "Quiet now")]
+    #[case::escaped("1 << 2", "1 < 2")]
+    #[case::not_a_tag("I <3 you, a < b, <Tag>", "I <3 you, a < b, <Tag>")]
+    fn strips_markup(#[case] text: &str, #[case] expected: &str) {
+        assert!(markup_spans(text).is_ok());
+        assert_eq!(strip_markup(text), expected);
+    }
+
+    #[rstest]
+    #[case::plain("Ana")]
+    #[case::tag("<b>Ana")]
+    #[case::escaped("a << b")]
+    fn escaped_text_is_shown_as_typed(#[case] text: &str) {
+        assert_eq!(strip_markup(&escape_markup(text)), text);
+    }
+
+    #[rstest]
+    #[case::unknown("<blink>Hi</blink>", MarkupError::Unknown("blink".into()))]
+    #[case::unclosed("<b>Hi", MarkupError::Unclosed("b".into()))]
+    #[case::unopened("Hi</b>", MarkupError::Unopened("b".into()))]
+    #[case::crossed("<b><i>Hi</b></i>", MarkupError::Unopened("b".into()))]
+    fn invalid_markup(#[case] text: &str, #[case] expected: MarkupError) {
+        assert_eq!(markup_spans(text).unwrap_err(), expected);
+    }
+}
@@ -0,0 +1,117 @@
+use derive_more::Display;
+use std::{collections::VecDeque, str::FromStr};
+
+use super::IllegalConversion;
+
+const DEFAULT_LOG_LENGTH: usize = 100;
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogLength(usize);
+
+impl LogLength {
+    pub const fn value(&self) -> usize {
+        self.0
+    }
+}
+
+impl Default for LogLength {
+    fn default() -> Self {
+        Self(DEFAULT_LOG_LENGTH)
+    }
+}
+
+impl FromStr for LogLength {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<usize>()
+            .map(Self)
+            .map_err(|_| IllegalConversion {
+                value: s.into(),
+                dtype: "LogLength",
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub heading: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageLog {
+    entries: VecDeque<LogEntry>,
+    length: LogLength,
+}
+
+impl MessageLog {
+    pub const fn new(length: LogLength) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            length,
+        }
+    }
+    pub fn push(&mut self, heading: &str, text: &str) {
+        let entry = LogEntry {
+            heading: heading.into(),
+            text: text.into(),
+        };
+        if self.length.value() == 0 || self.entries.back() == Some(&entry) {
+            return;
+        }
+        self.entries.push_back(entry);
+        while self.entries.len() > self.length.value() {
+            self.entries.pop_front();
+        }
+    }
+    pub fn entries(&self) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    fn texts(log: &MessageLog) -> Vec<&str> {
+        log.entries().map(|e| e.text.as_str()).collect()
+    }
+
+    #[test]
+    fn keeps_the_most_recent_entries() {
+        let mut log = MessageLog::new("2".parse().unwrap());
+        log.push("Basement", "A cold, damp basement.");
+        log.push("Open Crate", "You pry the crate open.");
+        log.push("Inventory", "Gained: Crowbar");
+        assert_eq!(texts(&log), ["You pry the crate open.", "Gained: Crowbar"]);
+    }
+
+    #[test]
+    fn skips_repeated_entries() {
+        let mut log = MessageLog::new(LogLength::default());
+        log.push("Old Man", "Hello!");
+        log.push("Old Man", "Hello!");
+        log.push("", "> Hi");
+        log.push("Old Man", "Hello!");
+        assert_eq!(texts(&log), ["Hello!", "> Hi", "Hello!"]);
+    }
+
+    #[test]
+    fn zero_length_disables_the_log() {
+        let mut log = MessageLog::new("0".parse().unwrap());
+        log.push("Basement", "A cold, damp basement.");
+        assert_eq!(log.entries().count(), 0);
+    }
+
+    #[rstest]
+    #[case::negative("-1")]
+    #[case::word("lots")]
+    fn invalid_log_length(#[case] input: &str) {
+        assert!(input.parse::<LogLength>().is_err());
+    }
+}
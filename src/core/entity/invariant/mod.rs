@@ -1,11 +1,41 @@
+mod affinity;
+mod alias;
+mod answer;
+mod clock;
 mod color;
 mod entity_name;
+mod markup;
+mod message_log;
+mod pronouns;
+mod quest_status;
+mod repeat;
+mod score;
+mod template;
+mod text_speed;
+mod timing;
+mod turn_range;
+mod variable;
 
 use derive_more::Display;
 use thiserror::Error;
 
+pub use affinity::*;
+pub use alias::*;
+pub use answer::*;
+pub use clock::*;
 pub use color::*;
 pub use entity_name::*;
+pub use markup::*;
+pub use message_log::*;
+pub use pronouns::*;
+pub use quest_status::*;
+pub use repeat::*;
+pub use score::*;
+pub use template::*;
+pub use text_speed::*;
+pub use timing::*;
+pub use turn_range::*;
+pub use variable::*;
 
 #[derive(Error, Debug, Display)]
 #[display("Cannot convert `{value}` to type {dtype}")]
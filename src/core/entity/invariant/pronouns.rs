@@ -0,0 +1,203 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::IllegalConversion;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PronounForm {
+    They,
+    Them,
+    Their,
+    Theirs,
+    Themself,
+    Are,
+    Were,
+    Have,
+    Do,
+    S,
+    Es,
+}
+
+impl FromStr for PronounForm {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "they" => Ok(Self::They),
+            "them" => Ok(Self::Them),
+            "their" => Ok(Self::Their),
+            "theirs" => Ok(Self::Theirs),
+            "themself" | "themselves" => Ok(Self::Themself),
+            "are" => Ok(Self::Are),
+            "were" => Ok(Self::Were),
+            "have" => Ok(Self::Have),
+            "do" => Ok(Self::Do),
+            "s" => Ok(Self::S),
+            "es" => Ok(Self::Es),
+            _ => Err(IllegalConversion {
+                value: s.into(),
+                dtype: "PronounForm",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PronounWord {
+    pub form: PronounForm,
+    pub capitalized: bool,
+}
+
+impl FromStr for PronounWord {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            form: s.parse()?,
+            capitalized: s.trim().starts_with(char::is_uppercase),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pronouns {
+    they: String,
+    them: String,
+    their: String,
+    theirs: String,
+    themself: String,
+    plural: bool,
+}
+
+impl Pronouns {
+    fn new(forms: [&str; 5], plural: bool) -> Self {
+        let [they, them, their, theirs, themself] = forms.map(String::from);
+        Self {
+            they,
+            them,
+            their,
+            theirs,
+            themself,
+            plural,
+        }
+    }
+    pub fn form(&self, form: PronounForm) -> &str {
+        let pick = |plural, singular| if self.plural { plural } else { singular };
+        match form {
+            PronounForm::They => &self.they,
+            PronounForm::Them => &self.them,
+            PronounForm::Their => &self.their,
+            PronounForm::Theirs => &self.theirs,
+            PronounForm::Themself => &self.themself,
+            PronounForm::Are => pick("are", "is"),
+            PronounForm::Were => pick("were", "was"),
+            PronounForm::Have => pick("have", "has"),
+            PronounForm::Do => pick("do", "does"),
+            PronounForm::S => pick("", "s"),
+            PronounForm::Es => pick("", "es"),
+        }
+    }
+    pub fn word(&self, word: PronounWord) -> String {
+        let form = self.form(word.form);
+        let mut chars = form.chars();
+        match chars.next() {
+            Some(first) if word.capitalized => first.to_uppercase().chain(chars).collect(),
+            _ => form.to_string(),
+        }
+    }
+}
+
+impl Default for Pronouns {
+    fn default() -> Self {
+        Self::new(["they", "them", "their", "theirs", "themself"], true)
+    }
+}
+
+impl Display for Pronouns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.they, self.them)
+    }
+}
+
+/// Built in sets as (usual short form, all five forms, plural)
+const KNOWN_PRONOUNS: [(&str, [&str; 5], bool); 4] = [
+    ("he/him", ["he", "him", "his", "his", "himself"], false),
+    ("she/her", ["she", "her", "her", "hers", "herself"], false),
+    (
+        "they/them",
+        ["they", "them", "their", "theirs", "themself"],
+        true,
+    ),
+    ("it/its", ["it", "it", "its", "its", "itself"], false),
+];
+
+impl FromStr for Pronouns {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+        let forms = lowercase.split('/').map(str::trim).collect::<Vec<_>>();
+        let known = KNOWN_PRONOUNS
+            .iter()
+            .find(|(short, all, _)| forms == [all[0]] || forms.join("/") == *short || forms == all);
+        match (known, forms.as_slice()) {
+            (Some((_, all, plural)), _) => Ok(Self::new(*all, *plural)),
+            (None, [they, them, their, theirs, themself])
+                if forms.iter().all(|f| !f.is_empty()) =>
+            {
+                Ok(Self::new([they, them, their, theirs, themself], false))
+            }
+            _ => Err(IllegalConversion {
+                value: s.into(),
+                dtype: "Pronouns",
+            }),
+        }
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::he("he/him", "he", "his")]
+    #[case::she("She/Her", "she", "hers")]
+    #[case::they("they/them", "they", "theirs")]
+    #[case::it("it", "it", "its")]
+    #[case::it_its("it/its", "it", "its")]
+    #[case::full_set("he/him/his/his/himself", "he", "his")]
+    #[case::custom("xe/xem/xyr/xyrs/xemself", "xe", "xyrs")]
+    fn valid_pronouns(#[case] input: &str, #[case] they: &str, #[case] theirs: &str) {
+        let pronouns = input.parse::<Pronouns>().unwrap();
+        assert_eq!(pronouns.form(PronounForm::They), they);
+        assert_eq!(pronouns.form(PronounForm::Theirs), theirs);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown("xe/xem")]
+    #[case::blank_form("xe/xem//xyrs/xemself")]
+    #[case::unknown_trailing_form("he/xyz")]
+    #[case::extra_form("she/her/xyz")]
+    #[case::mixed_sets("he/her")]
+    fn invalid_pronouns(#[case] input: &str) {
+        assert!(input.parse::<Pronouns>().is_err());
+    }
+
+    #[rstest]
+    #[case::singular_are("he/him", "are", "is")]
+    #[case::plural_are("they/them", "are", "are")]
+    #[case::singular_were("she/her", "were", "was")]
+    #[case::plural_have("they/them", "have", "have")]
+    #[case::custom_does("xe/xem/xyr/xyrs/xemself", "do", "does")]
+    #[case::singular_suffix("it/its", "s", "s")]
+    #[case::plural_suffix("they/them", "es", "")]
+    #[case::capitalized("she/her", "They", "She")]
+    #[case::capitalized_verb("they/them", "Are", "Are")]
+    fn pronoun_words(#[case] pronouns: &str, #[case] word: &str, #[case] expected: &str) {
+        let pronouns = pronouns.parse::<Pronouns>().unwrap();
+        assert_eq!(pronouns.word(word.parse().unwrap()), expected);
+    }
+}
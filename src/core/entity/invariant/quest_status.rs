@@ -0,0 +1,56 @@
+use derive_more::Display;
+use std::str::FromStr;
+
+use super::IllegalConversion;
+
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuestStatus {
+    #[default]
+    #[display("not_started")]
+    NotStarted,
+    #[display("active")]
+    Active,
+    #[display("completed")]
+    Completed,
+    #[display("failed")]
+    Failed,
+}
+
+impl FromStr for QuestStatus {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "not_started" => Ok(Self::NotStarted),
+            "active" => Ok(Self::Active),
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            _ => Err(IllegalConversion {
+                value: s.into(),
+                dtype: "QuestStatus",
+            }),
+        }
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::not_started("not_started", QuestStatus::NotStarted)]
+    #[case::active("Active", QuestStatus::Active)]
+    #[case::completed("completed", QuestStatus::Completed)]
+    #[case::failed("failed", QuestStatus::Failed)]
+    fn valid_quest_status(#[case] input: &str, #[case] expected: QuestStatus) {
+        assert_eq!(input.parse::<QuestStatus>().unwrap(), expected);
+    }
+
+    #[test]
+    fn invalid_quest_status() {
+        assert!("done".parse::<QuestStatus>().is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use derive_more::Display;
+use std::str::FromStr;
+
+use super::IllegalConversion;
+
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Repeat {
+    #[display("{_0}")]
+    Times(usize),
+    #[default]
+    #[display("always")]
+    Always,
+}
+
+impl Repeat {
+    pub const fn is_exhausted(&self, uses: usize) -> bool {
+        match self {
+            Self::Times(limit) => uses >= *limit,
+            Self::Always => false,
+        }
+    }
+}
+
+impl FromStr for Repeat {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "once" => Ok(Self::Times(1)),
+            "always" => Ok(Self::Always),
+            n => match n.parse::<usize>() {
+                Ok(n) if n > 0 => Ok(Self::Times(n)),
+                _ => Err(IllegalConversion {
+                    value: s.into(),
+                    dtype: "Repeat",
+                }),
+            },
+        }
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::once("once", Repeat::Times(1))]
+    #[case::always("Always", Repeat::Always)]
+    #[case::count("3", Repeat::Times(3))]
+    fn valid_repeat(#[case] input: &str, #[case] expected: Repeat) {
+        assert_eq!(input.parse::<Repeat>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::zero("0")]
+    #[case::negative("-1")]
+    #[case::word("twice")]
+    fn invalid_repeat(#[case] input: &str) {
+        assert!(input.parse::<Repeat>().is_err());
+    }
+
+    #[test]
+    fn exhaustion() {
+        assert!(!Repeat::Times(2).is_exhausted(1));
+        assert!(Repeat::Times(2).is_exhausted(2));
+        assert!(!Repeat::Always.is_exhausted(usize::MAX));
+    }
+}
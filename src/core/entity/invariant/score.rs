@@ -0,0 +1,51 @@
+use derive_more::Display;
+use std::str::FromStr;
+
+use super::IllegalConversion;
+
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Score(i32);
+
+impl Score {
+    pub const fn points(&self) -> i32 {
+        self.0
+    }
+}
+
+impl FromStr for Score {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<i32>()
+            .map(Self)
+            .map_err(|_| IllegalConversion {
+                value: s.into(),
+                dtype: "Score",
+            })
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::plus("+10", 10)]
+    #[case::minus("-5", -5)]
+    #[case::bare(" 3 ", 3)]
+    fn valid_score(#[case] input: &str, #[case] expected: i32) {
+        assert_eq!(input.parse::<Score>().unwrap().points(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::word("ten")]
+    #[case::fraction("1.5")]
+    fn invalid_score(#[case] input: &str) {
+        assert!(input.parse::<Score>().is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use std::str::FromStr;
+
+use super::{Identifier, IllegalConversion, PronounWord, Title};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameVar {
+    Score,
+    Turns,
+    Time,
+    Room,
+    Items,
+    Custom(Identifier),
+}
+
+impl FromStr for GameVar {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "score" => Ok(Self::Score),
+            "turns" => Ok(Self::Turns),
+            "time" => Ok(Self::Time),
+            "room" => Ok(Self::Room),
+            "items" => Ok(Self::Items),
+            name => Ok(Self::Custom(name.parse()?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterField {
+    Name,
+    Affinity,
+    Pronoun(PronounWord),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Placeholder {
+    PlayerName,
+    PlayerPronoun(PronounWord),
+    Var(GameVar),
+    Item {
+        item: Identifier,
+        /// Renders `1` if the item is held and `0` if not, as the inventory holds one of each item
+        count: bool,
+    },
+    Character {
+        character: Title,
+        field: CharacterField,
+    },
+}
+
+impl FromStr for Placeholder {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IllegalConversion {
+            value: s.into(),
+            dtype: "Placeholder",
+        };
+        let s = s.trim();
+        if s == "player_name" {
+            return Ok(Self::PlayerName);
+        }
+        // `OldMan.they` and `player.they` are short for `char:OldMan.they` and `player:they`
+        let (kind, name) = match s.split_once(':') {
+            Some(parts) => parts,
+            None => match s.split_once('.') {
+                Some((player, field)) if player.trim() == "player" => ("player", field),
+                Some(_) => ("char", s),
+                None => return Err(error()),
+            },
+        };
+        let (name, field) = match name.split_once('.') {
+            Some((name, field)) => (name.trim(), Some(field.trim())),
+            None => (name.trim(), None),
+        };
+        match (kind.trim(), field) {
+            ("var", None) => Ok(Self::Var(name.parse().map_err(|_| error())?)),
+            ("player", None) => Ok(Self::PlayerPronoun(name.parse().map_err(|_| error())?)),
+            ("item", None | Some("count")) => Ok(Self::Item {
+                item: name.parse().map_err(|_| error())?,
+                count: field.is_some(),
+            }),
+            ("char", field) => Ok(Self::Character {
+                character: name.parse().map_err(|_| error())?,
+                field: match field {
+                    None => CharacterField::Name,
+                    Some("affinity") => CharacterField::Affinity,
+                    Some(word) => CharacterField::Pronoun(word.parse().map_err(|_| error())?),
+                },
+            }),
+            _ => Err(error()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+pub fn template_segments(text: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(['{', '}']) {
+        let (before, after) = rest.split_at(start);
+        if !before.is_empty() {
+            segments.push(Segment::Text(before));
+        }
+        if let Some(after) = after.strip_prefix("{{") {
+            segments.push(Segment::Text("{"));
+            rest = after;
+        } else if let Some(after) = after.strip_prefix("}}") {
+            segments.push(Segment::Text("}"));
+            rest = after;
+        } else if let Some(after) = after.strip_prefix('{')
+            && let Some(end) = after.find('}')
+        {
+            segments.push(Segment::Placeholder(&after[..end]));
+            rest = &after[end + 1..];
+        } else {
+            segments.push(Segment::Text(&after[..1]));
+            rest = &after[1..];
+        }
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+    segments
+}
+
+pub fn render_template(text: &str, value: impl Fn(&Placeholder) -> String) -> String {
+    template_segments(text)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text.to_string(),
+            Segment::Placeholder(inner) => inner
+                .parse::<Placeholder>()
+                .map_or_else(|_| format!("{{{inner}}}"), |p| value(&p)),
+        })
+        .collect()
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::player_name("player_name", Placeholder::PlayerName)]
+    #[case::player_pronoun("player:their", Placeholder::PlayerPronoun("their".parse().unwrap()))]
+    #[case::player_shorthand("player.Are", Placeholder::PlayerPronoun("Are".parse().unwrap()))]
+    #[case::var("var:score", Placeholder::Var(GameVar::Score))]
+    #[case::room("var:room", Placeholder::Var(GameVar::Room))]
+    #[case::custom_var("var:gold", Placeholder::Var(GameVar::Custom("gold".parse().unwrap())))]
+    #[case::item("item:coin", Placeholder::Item { item: "coin".parse().unwrap(), count: false })]
+    #[case::item_count("item:coin.count", Placeholder::Item { item: "coin".parse().unwrap(), count: true })]
+    #[case::character("char:OldMan", Placeholder::Character { character: "OldMan".parse().unwrap(), field: CharacterField::Name })]
+    #[case::affinity("char: OldMan.affinity ", Placeholder::Character { character: "OldMan".parse().unwrap(), field: CharacterField::Affinity })]
+    #[case::pronoun("char:OldMan.they", Placeholder::Character { character: "OldMan".parse().unwrap(), field: CharacterField::Pronoun("they".parse().unwrap()) })]
+    #[case::pronoun_shorthand("OldMan.are", Placeholder::Character { character: "OldMan".parse().unwrap(), field: CharacterField::Pronoun("are".parse().unwrap()) })]
+    fn valid_placeholder(#[case] input: &str, #[case] expected: Placeholder) {
+        assert_eq!(input.parse::<Placeholder>().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown_kind("gold")]
+    #[case::bad_var_name("var:Gold Coins")]
+    #[case::unknown_field("item:coin.weight")]
+    #[case::unknown_character_field("OldMan.weight")]
+    #[case::shorthand_without_field("OldMan")]
+    #[case::var_field("var:score.count")]
+    #[case::unknown_pronoun("player:xe")]
+    fn invalid_placeholder(#[case] input: &str) {
+        let error = input.parse::<Placeholder>().unwrap_err();
+        assert!(
+            matches!(&error, IllegalConversion { value, dtype: "Placeholder" } if value == input),
+            "Wrong error for `{input}`: {error:?}"
+        );
+    }
+
+    #[rstest]
+    #[case::plain("Hello there!", "Hello there!")]
+    #[case::placeholders(
+        "Hi {player_name}, you have {var:score} points",
+        "Hi Ada, you have 7 points"
+    )]
+    #[case::escaped("Use {{braces}} like {{this}}", "Use {braces} like {this}")]
+    #[case::unclosed("A lone { brace", "A lone { brace")]
+    #[case::unknown("Keep {weather} as is", "Keep {weather} as is")]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn renders_template(#[case] text: &str, #[case] expected: &str) {
+        let rendered = render_template(text, |p| match p {
+            Placeholder::PlayerName => "Ada".into(),
+            _ => "7".into(),
+        });
+        assert_eq!(rendered, expected);
+    }
+}
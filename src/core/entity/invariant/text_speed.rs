@@ -0,0 +1,27 @@
+use derive_more::Display;
+use std::str::FromStr;
+
+use super::IllegalConversion;
+
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextSpeed(u32);
+
+impl TextSpeed {
+    pub const fn chars_per_second(&self) -> u32 {
+        self.0
+    }
+}
+
+impl FromStr for TextSpeed {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<u32>()
+            .map(Self)
+            .map_err(|_| IllegalConversion {
+                value: s.into(),
+                dtype: "TextSpeed",
+            })
+    }
+}
@@ -0,0 +1,48 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timing {
+    after: usize,
+    every: Option<usize>,
+}
+
+impl Timing {
+    pub fn new(after: Option<usize>, every: Option<usize>) -> Option<Self> {
+        let every = every.filter(|e| *e > 0);
+        let after = after.or(every)?;
+        Some(Self { after, every })
+    }
+    pub fn is_due(&self, turn: usize) -> bool {
+        turn == self.after
+            || turn > self.after
+                && self
+                    .every
+                    .is_some_and(|every| (turn - self.after).is_multiple_of(every))
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::after(Some(3), None, vec![3])]
+    #[case::every(None, Some(4), vec![4, 8, 12])]
+    #[case::after_then_every(Some(2), Some(5), vec![2, 7, 12])]
+    fn timing_is_due(
+        #[case] after: Option<usize>,
+        #[case] every: Option<usize>,
+        #[case] expected: Vec<usize>,
+    ) {
+        let timing = Timing::new(after, every).unwrap();
+        let due = (0..=12).filter(|t| timing.is_due(*t)).collect::<Vec<_>>();
+        assert_eq!(due, expected);
+    }
+
+    #[test]
+    fn timing_needs_a_turn() {
+        assert_eq!(Timing::new(None, None), None);
+        assert_eq!(Timing::new(None, Some(0)), None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use super::IllegalConversion;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TurnRange {
+    from: Option<usize>,
+    to: Option<usize>,
+}
+
+impl TurnRange {
+    pub fn contains(&self, turn: usize) -> bool {
+        self.from.is_none_or(|from| turn >= from) && self.to.is_none_or(|to| turn <= to)
+    }
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let from = self.from.max(other.from).unwrap_or_default();
+        self.to
+            .into_iter()
+            .chain(other.to)
+            .min()
+            .is_none_or(|to| from <= to)
+    }
+}
+
+impl FromStr for TurnRange {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IllegalConversion {
+            value: s.into(),
+            dtype: "TurnRange",
+        };
+        let bound = |b: &str| {
+            let b = b.trim();
+            if b.is_empty() {
+                Ok(None)
+            } else {
+                b.parse::<usize>().map(Some).map_err(|_| error())
+            }
+        };
+        let (from, to) = match s.split_once("..") {
+            Some((from, to)) => (bound(from)?, bound(to)?),
+            None => {
+                let turn = bound(s)?.ok_or_else(error)?;
+                (Some(turn), Some(turn))
+            }
+        };
+        if from.is_none() && to.is_none() || from.zip(to).is_some_and(|(f, t)| f > t) {
+            return Err(error());
+        }
+        Ok(Self { from, to })
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::exact("5", 5, true)]
+    #[case::exact_miss("5", 6, false)]
+    #[case::range_start("3..6", 3, true)]
+    #[case::range_end("3..6", 6, true)]
+    #[case::range_after("3..6", 7, false)]
+    #[case::open_end("10..", 99, true)]
+    #[case::open_start("..10", 0, true)]
+    #[case::open_start_miss("..10", 11, false)]
+    fn turn_range_contains(#[case] input: &str, #[case] turn: usize, #[case] expected: bool) {
+        let range = input.parse::<TurnRange>().unwrap();
+        assert_eq!(range.contains(turn), expected, "Failed for `{input}`");
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unbounded("..")]
+    #[case::backwards("6..3")]
+    #[case::negative("-1..3")]
+    #[case::word("soon")]
+    fn invalid_turn_range(#[case] input: &str) {
+        assert!(input.parse::<TurnRange>().is_err());
+    }
+
+    #[rstest]
+    #[case::disjoint("..4", "5..", false)]
+    #[case::touching("..5", "5..", true)]
+    #[case::nested("1..10", "3..4", true)]
+    #[case::unbounded("3..", "10..", true)]
+    fn turn_range_overlaps(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        let a = a.parse::<TurnRange>().unwrap();
+        let b = b.parse::<TurnRange>().unwrap();
+        assert_eq!(a.overlaps(&b), expected);
+        assert_eq!(b.overlaps(&a), expected);
+    }
+}
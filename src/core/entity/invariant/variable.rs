@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use super::{GameVar, Identifier, IllegalConversion};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VarChange {
+    Add(Identifier, i32),
+    Set(Identifier, i32),
+}
+
+impl VarChange {
+    pub const fn var(&self) -> &Identifier {
+        match self {
+            Self::Add(var, _) | Self::Set(var, _) => var,
+        }
+    }
+    pub const fn apply(&self, value: i32) -> i32 {
+        match self {
+            Self::Add(_, amount) => value.saturating_add(*amount),
+            Self::Set(_, amount) => *amount,
+        }
+    }
+}
+
+impl FromStr for VarChange {
+    type Err = IllegalConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IllegalConversion {
+            value: s.into(),
+            dtype: "VarChange",
+        };
+        let (var, amount, set) = match s.split_once('=') {
+            Some((var, amount)) => (var, amount, true),
+            None => {
+                let split = s.rfind(['+', '-']).ok_or_else(error)?;
+                let (var, amount) = s.split_at(split);
+                (var, amount, false)
+            }
+        };
+        // Built-in variables like `score` can't be changed this way
+        let GameVar::Custom(var) = var.parse::<GameVar>().map_err(|_| error())? else {
+            return Err(error());
+        };
+        let amount = amount
+            .split_whitespace()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| error())?;
+        Ok(if set {
+            Self::Set(var, amount)
+        } else {
+            Self::Add(var, amount)
+        })
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::gain("gold+10", 5, 15)]
+    #[case::loss("gold - 3", 5, 2)]
+    #[case::set("gold=0", 5, 0)]
+    #[case::set_negative("gold = -2", 5, -2)]
+    fn valid_var_change(#[case] input: &str, #[case] value: i32, #[case] expected: i32) {
+        let change = input.parse::<VarChange>().unwrap();
+        assert_eq!(change.var().to_string(), "gold");
+        assert_eq!(change.apply(value), expected);
+    }
+
+    #[rstest]
+    #[case::no_amount("gold")]
+    #[case::no_name("+2")]
+    #[case::word_amount("gold+ten")]
+    #[case::built_in("score+5")]
+    #[case::bad_name("Gold Coins+1")]
+    fn invalid_var_change(#[case] input: &str) {
+        assert!(input.parse::<VarChange>().is_err());
+    }
+}
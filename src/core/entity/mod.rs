@@ -1,15 +1,21 @@
+mod achievement;
 mod action;
 mod character;
 pub mod database;
+mod fragment;
 mod invariant;
 mod proxy;
+mod quest;
 mod room;
 mod title;
 
+pub use achievement::*;
 pub use action::*;
 pub use character::*;
 pub use database::*;
+pub use fragment::*;
 pub use invariant::*;
 pub use proxy::*;
+pub use quest::*;
 pub use room::*;
 pub use title::*;
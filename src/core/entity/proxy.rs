@@ -74,6 +74,7 @@ macro_rules! define_id_and_proxy {
             pub const fn into_id(self) -> $id {
                 self.id
             }
+            #[allow(dead_code)]
             pub fn id(&self) -> $id {
                 self.id.clone()
             }
@@ -0,0 +1,40 @@
+use crate::{define_id, define_id_and_proxy};
+
+use super::{
+    IntoProxy,
+    database::Lookup,
+    invariant::{Identifier, QuestStatus, Title},
+};
+
+define_id_and_proxy!(QuestId, Quest);
+define_id!(QuestStageId);
+
+#[derive(Debug)]
+pub struct QuestRaw {
+    pub name: Title,
+    pub variant: Option<Identifier>,
+    pub description: String,
+}
+
+pub type QuestEntity = Vec<QuestStageEntity>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct QuestStageEntity {
+    pub name: String,
+    pub description: String,
+}
+
+impl<'a, DB: Lookup> Quest<'a, DB> {
+    fn stage(&self) -> &QuestStageEntity {
+        self.db.lookup_quest(self.id)
+    }
+    pub fn name(&self) -> String {
+        self.db.render(&self.stage().name)
+    }
+    pub fn description(&self) -> String {
+        self.db.render(&self.stage().description)
+    }
+    pub fn status(&self) -> QuestStatus {
+        self.db.quest_status(self.id)
+    }
+}
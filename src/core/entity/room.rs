@@ -5,19 +5,30 @@ use indexmap::IndexMap;
 use crate::{define_id, define_id_and_proxy};
 
 use super::{
-    Action, ActionId, Character, CharacterId, Database, IntoProxy,
+    Action, ActionId, Character, CharacterId, Database, FragmentId, IntoProxy, Requirement,
+    RequirementRaw,
     database::Lookup,
-    invariant::{Identifier, Title},
+    fragment::with_fragments,
+    invariant::{Alias, Identifier, Minutes, Title},
 };
 
 define_id!(ItemId);
 define_id_and_proxy!(RoomId, Room);
 define_id!(RoomVariantId);
 
+#[derive(Getters, new, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ItemRaw {
+    pub name: Identifier,
+    pub description: String,
+    pub fragments: Vec<Identifier>,
+    pub aliases: Vec<Alias>,
+}
 #[derive(Getters, new, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Item {
     pub name: Identifier,
     pub description: String,
+    pub fragments: Vec<FragmentId>,
+    pub aliases: Vec<Alias>,
 }
 
 #[derive(Debug)]
@@ -25,9 +36,17 @@ pub struct RoomRaw {
     pub name: Title,
     pub variant: Option<Identifier>,
     pub description: String,
+    pub first_description: Option<String>,
+    pub short_description: Option<String>,
+    pub art: Option<String>,
     pub characters: Vec<Title>,
     pub exits: IndexMap<Identifier, Title>,
+    pub exit_aliases: IndexMap<Identifier, Vec<Alias>>,
+    pub travel_times: IndexMap<Identifier, Minutes>,
     pub actions: Vec<Identifier>,
+    pub fragments: Vec<Identifier>,
+    pub requires: Vec<RequirementRaw>,
+    pub priority: i32,
 }
 
 pub type RoomEntity = Vec<RoomVariantEntity>;
@@ -36,14 +55,46 @@ pub type RoomEntity = Vec<RoomVariantEntity>;
 pub struct RoomVariantEntity {
     pub name: String,
     pub description: String,
+    pub first_description: Option<String>,
+    pub short_description: Option<String>,
+    pub art: Option<String>,
     pub characters: Vec<CharacterId>,
     pub exits: IndexMap<Identifier, RoomId>,
+    pub exit_aliases: IndexMap<Identifier, Vec<Alias>>,
+    pub travel_times: IndexMap<Identifier, Minutes>,
     pub actions: Vec<ActionId>,
+    pub fragments: Vec<FragmentId>,
+    pub requires: Vec<Requirement>,
+    pub priority: i32,
+}
+
+impl RoomVariantEntity {
+    pub fn is_ambiguous_with(&self, other: &Self) -> bool {
+        Requirement::are_ambiguous(
+            (&self.requires, self.priority),
+            (&other.requires, other.priority),
+        )
+    }
+    pub fn travel_time(&self, to: RoomId) -> Option<Minutes> {
+        self.exits
+            .iter()
+            .find(|(_, id)| **id == to)
+            .and_then(|(direction, _)| self.travel_times.get(direction).copied())
+    }
+    pub fn exit_aliases(&self, direction: &Identifier) -> impl Iterator<Item = Alias> {
+        self.exit_aliases
+            .get(direction)
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain(Alias::compass(direction))
+    }
 }
 
 #[derive(Getters)]
 pub struct Exit<'a, T: Lookup> {
     direction: Identifier,
+    aliases: Vec<Alias>,
     room: Room<'a, T>,
 }
 
@@ -57,21 +108,52 @@ impl<'a, DB: Lookup> Room<'a, DB> {
     pub fn name(&self) -> &str {
         self.room().name.as_str()
     }
-    pub fn description(&self) -> &str {
-        self.room().description.as_str()
+    pub fn description(&self) -> String {
+        let room = self.room();
+        let description = if self.db.room_visits(self.id) <= 1 {
+            room.first_description.as_ref()
+        } else if self.db.is_verbose() {
+            None
+        } else {
+            room.short_description.as_ref()
+        };
+        with_fragments(
+            self.db,
+            description.unwrap_or(&room.description),
+            &room.fragments,
+        )
+    }
+    pub fn art(&self) -> Option<&str> {
+        self.room().art.as_deref()
+    }
+    pub fn has_short_description(&self) -> bool {
+        self.room().short_description.is_some()
     }
     pub fn actions(&self) -> impl Iterator<Item = Action<'a, DB>> {
-        self.room().actions.iter().map(|id| id.into_proxy(self.db))
+        self.room()
+            .actions
+            .iter()
+            .filter(|id| !self.db.is_action_exhausted(**id))
+            .map(|id| id.into_proxy(self.db))
     }
-    pub fn characters(&self) -> impl Iterator<Item = Character<'_, DB>> {
+    pub fn exhausted_actions(&self) -> impl Iterator<Item = Action<'a, DB>> {
         self.room()
-            .characters
+            .actions
             .iter()
+            .filter(|id| self.db.is_action_exhausted(**id))
+            .map(|id| id.into_proxy(self.db))
+    }
+    pub fn characters(&self) -> impl Iterator<Item = Character<'_, DB>> {
+        self.db
+            .characters_in(self.id)
+            .into_iter()
             .map(|id| id.into_proxy(self.db))
     }
     pub fn exits(&self) -> impl Iterator<Item = Exit<'_, DB>> {
-        self.room().exits.iter().map(|(direction, id)| Exit {
+        let room = self.room();
+        room.exits.iter().map(|(direction, id)| Exit {
             direction: direction.clone(),
+            aliases: room.exit_aliases(direction).collect(),
             room: id.into_proxy(self.db),
         })
     }
@@ -1,7 +1,9 @@
 use bon::Builder;
 use derive_getters::Getters;
 
-use super::{RoomId, ThemeColor, Title};
+use super::{
+    ClockTime, LogLength, Minutes, Pronouns, RoomId, Score, TextSpeed, ThemeColor, ThemeSlot, Title,
+};
 
 #[derive(Debug)]
 pub struct GameTitleRaw {
@@ -9,6 +11,17 @@ pub struct GameTitleRaw {
     pub greeting: String,
     pub credits: String,
     pub start_room: Title,
+    pub show_exhausted: bool,
+    pub max_score: Option<Score>,
+    pub start_time: Option<ClockTime>,
+    pub minutes_per_turn: Option<Minutes>,
+    pub player_name: Option<String>,
+    pub player_pronouns: Option<Pronouns>,
+    pub ask_player_name: bool,
+    pub ask_pronouns: bool,
+    pub art: Option<String>,
+    pub log_length: Option<LogLength>,
+    pub status_bar: Option<String>,
 }
 
 #[derive(Getters, Builder, Debug, PartialEq, Eq)]
@@ -17,6 +30,20 @@ pub struct GameTitle {
     greeting: String,
     credits: String,
     start_room: RoomId,
+    #[builder(default)]
+    show_exhausted: bool,
+    max_score: Option<Score>,
+    start_time: Option<ClockTime>,
+    minutes_per_turn: Option<Minutes>,
+    player_name: Option<String>,
+    player_pronouns: Option<Pronouns>,
+    #[builder(default)]
+    ask_player_name: bool,
+    #[builder(default)]
+    ask_pronouns: bool,
+    art: Option<String>,
+    log_length: Option<LogLength>,
+    status_bar: Option<String>,
 }
 
 #[derive(Getters, Builder, Debug, PartialEq, Eq)]
@@ -28,9 +55,28 @@ pub struct Theme {
     highlight: ThemeColor,
     highlight_text: ThemeColor,
     subdued: ThemeColor,
+    status: ThemeColor,
+    status_background: ThemeColor,
+    text_speed: Option<TextSpeed>,
 }
 
-#[derive(Getters, Builder, Debug, PartialEq, Eq)]
+impl Theme {
+    pub const fn color(&self, slot: ThemeSlot) -> &ThemeColor {
+        match slot {
+            ThemeSlot::Title => &self.title,
+            ThemeSlot::Heading => &self.heading,
+            ThemeSlot::Background => &self.background,
+            ThemeSlot::Text => &self.text,
+            ThemeSlot::Highlight => &self.highlight,
+            ThemeSlot::HighlightText => &self.highlight_text,
+            ThemeSlot::Subdued => &self.subdued,
+            ThemeSlot::Status => &self.status,
+            ThemeSlot::StatusBackground => &self.status_background,
+        }
+    }
+}
+
+#[derive(Getters, Builder, Debug, Clone, PartialEq, Eq)]
 pub struct Language {
     characters_found: String,
     exits_found: String,
@@ -51,6 +97,80 @@ pub struct Language {
     action_failed: String,
     continue_game: String,
     press_q_to_quit: String,
+    verbose_descriptions: String,
+    brief_descriptions: String,
+    unknown_command: String,
+    known_commands: String,
+    enter_answer: String,
+    view_journal: String,
+    journal: String,
+    quests_active: String,
+    quests_completed: String,
+    quests_failed: String,
+    final_score: String,
+    achievements_unlocked: String,
+    current_time: String,
+    back_response: String,
+    view_history: String,
+    player_name_prompt: String,
+    pronouns_prompt: String,
+    unknown_pronouns: String,
+    message_log: String,
+    press_l_for_log: String,
+    item_gained: String,
+    item_lost: String,
+    press_esc_to_quit: String,
+    press_ctrl_l_for_log: String,
+}
+
+impl Language {
+    pub const fn strings_mut(&mut self) -> [(&'static str, &mut String); 43] {
+        [
+            ("characters_found", &mut self.characters_found),
+            ("exits_found", &mut self.exits_found),
+            ("talk", &mut self.talk),
+            ("interact", &mut self.interact),
+            ("go_somewhere", &mut self.go_somewhere),
+            ("view_inventory", &mut self.view_inventory),
+            ("inventory", &mut self.inventory),
+            ("end_game", &mut self.end_game),
+            ("choose_exit", &mut self.choose_exit),
+            ("cancel_exit", &mut self.cancel_exit),
+            ("choose_chat", &mut self.choose_chat),
+            ("cancel_chat", &mut self.cancel_chat),
+            ("choose_response", &mut self.choose_response),
+            ("cancel_response", &mut self.cancel_response),
+            ("choose_action", &mut self.choose_action),
+            ("cancel_action", &mut self.cancel_action),
+            ("action_failed", &mut self.action_failed),
+            ("continue_game", &mut self.continue_game),
+            ("press_q_to_quit", &mut self.press_q_to_quit),
+            ("verbose_descriptions", &mut self.verbose_descriptions),
+            ("brief_descriptions", &mut self.brief_descriptions),
+            ("unknown_command", &mut self.unknown_command),
+            ("known_commands", &mut self.known_commands),
+            ("enter_answer", &mut self.enter_answer),
+            ("view_journal", &mut self.view_journal),
+            ("journal", &mut self.journal),
+            ("quests_active", &mut self.quests_active),
+            ("quests_completed", &mut self.quests_completed),
+            ("quests_failed", &mut self.quests_failed),
+            ("final_score", &mut self.final_score),
+            ("achievements_unlocked", &mut self.achievements_unlocked),
+            ("current_time", &mut self.current_time),
+            ("back_response", &mut self.back_response),
+            ("view_history", &mut self.view_history),
+            ("player_name_prompt", &mut self.player_name_prompt),
+            ("pronouns_prompt", &mut self.pronouns_prompt),
+            ("unknown_pronouns", &mut self.unknown_pronouns),
+            ("message_log", &mut self.message_log),
+            ("press_l_for_log", &mut self.press_l_for_log),
+            ("item_gained", &mut self.item_gained),
+            ("item_lost", &mut self.item_lost),
+            ("press_esc_to_quit", &mut self.press_esc_to_quit),
+            ("press_ctrl_l_for_log", &mut self.press_ctrl_l_for_log),
+        ]
+    }
 }
 
 impl Default for Theme {
@@ -63,6 +183,9 @@ impl Default for Theme {
             highlight: ThemeColor::new(40, 40, 40),
             highlight_text: ThemeColor::new(255, 255, 80),
             subdued: ThemeColor::new(127, 127, 127),
+            status: ThemeColor::new(240, 240, 240),
+            status_background: ThemeColor::new(40, 40, 40),
+            text_speed: None,
         }
     }
 }
@@ -88,6 +211,31 @@ impl Default for Language {
             action_failed: "Nothing happened...".into(),
             continue_game: "Continue...".into(),
             press_q_to_quit: "Press 'q' at any time to quit!".into(),
+            verbose_descriptions: "Show full descriptions".into(),
+            brief_descriptions: "Show brief descriptions".into(),
+            unknown_command: "I don't understand that.".into(),
+            known_commands: "You can try:".into(),
+            enter_answer: "Your answer:".into(),
+            view_journal: "View journal".into(),
+            journal: "Journal".into(),
+            quests_active: "In progress:".into(),
+            quests_completed: "Completed:".into(),
+            quests_failed: "Failed:".into(),
+            final_score: "Final score:".into(),
+            achievements_unlocked: "Achievements unlocked:".into(),
+            current_time: "Time:".into(),
+            back_response: "Back".into(),
+            view_history: "What was said?".into(),
+            player_name_prompt: "What is your name?".into(),
+            pronouns_prompt: "What are your pronouns? For example: she/her, he/him or they/them."
+                .into(),
+            unknown_pronouns: "Sorry, those pronouns weren't recognized.".into(),
+            message_log: "What happened so far".into(),
+            press_l_for_log: "Press 'l' to look back at what happened.".into(),
+            item_gained: "Gained:".into(),
+            item_lost: "Lost:".into(),
+            press_esc_to_quit: "Press Esc at any time to quit!".into(),
+            press_ctrl_l_for_log: "Press Ctrl-L to look back at what happened.".into(),
         }
     }
 }
@@ -4,13 +4,20 @@ mod world;
 
 #[allow(unused_imports)]
 pub use entity::{
-    Action, ActionEntity, ActionId, ActionRaw, ChangeRoom, ChangeRoomRaw, Character,
-    CharacterEntity, CharacterId, CharacterRaw, Database, Dialogue, DialogueEntity, DialogueId,
-    DialogueRaw, DialogueVariantEntity, DialogueVariantId, GameTitle, GameTitleRaw, GiveItem,
-    GiveItemRaw, Identifier, IllegalConversion, IntoProxy, Item, ItemId, Language, Lookup,
-    ReplaceItem, ReplaceItemRaw, Requirement, RequirementRaw, Response, ResponseEntity, ResponseId,
-    ResponseRaw, Room, RoomEntity, RoomId, RoomRaw, RoomVariantEntity, RoomVariantId, Sequence,
-    SequenceRaw, TakeItem, TakeItemRaw, Teleport, TeleportRaw, Theme, ThemeColor, Title, Update,
+    AchievementEntity, AchievementId, AchievementRaw, Action, ActionCommon, ActionCommonRaw,
+    ActionEntity, ActionId, ActionRaw, AffinityChange, Alias, Answer, ChangeParty, ChangePartyRaw,
+    ChangeQuest, ChangeQuestRaw, ChangeRoom, ChangeRoomRaw, ChangeVar, ChangeVarRaw, Character,
+    CharacterEntity, CharacterId, CharacterRaw, ClockTime, Database, Dialogue, DialogueEntity,
+    DialogueId, DialogueRaw, DialogueVariantEntity, DialogueVariantId, FragmentEntity, FragmentId,
+    FragmentRaw, GameTitle, GameTitleRaw, GameVar, GiveItem, GiveItemRaw, Identifier,
+    IllegalConversion, IntoProxy, Item, ItemId, ItemRaw, Language, LogEntry, LogLength, Lookup,
+    MessageLog, Minutes, Placeholder, Pronouns, Quest, QuestEntity, QuestId, QuestRaw,
+    QuestStageEntity, QuestStageId, QuestStatus, Repeat, ReplaceItem, ReplaceItemRaw, Requirement,
+    RequirementRaw, Response, ResponseEntity, ResponseId, ResponseRaw, Room, RoomEntity, RoomId,
+    RoomRaw, RoomVariantEntity, RoomVariantId, Score, Segment, Sequence, SequenceRaw, StartTimer,
+    StartTimerRaw, TakeItem, TakeItemRaw, Teleport, TeleportRaw, TextSpeed, TextStyle, Theme,
+    ThemeColor, ThemeSlot, Threshold, TimeOfDay, Timing, Title, TurnRange, Update, VarChange,
+    escape_markup, markup_spans, strip_markup, template_segments,
 };
 pub use state::GameState;
 pub use world::World;
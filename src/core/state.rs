@@ -1,21 +1,49 @@
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     rc::Rc,
 };
 
 use ini::Ini;
-use tracing::info;
+use tracing::{debug, info};
 
 use crate::{config_parser, error};
 
 use super::{Lookup, Update, World, entity::*};
 
+const DEFAULT_MINUTES_PER_TURN: u32 = 1;
+const DEFAULT_PLAYER_NAME: &str = "Traveler";
+
 #[derive(Debug)]
 pub struct GameState {
     world: World,
     current_room: RoomId,
     inventory: BTreeSet<ItemId>,
     active_room_variants: HashMap<RoomId, RoomVariantId>,
+    room_visits: HashMap<RoomId, usize>,
+    dialogue_views: HashMap<DialogueId, usize>,
+    action_uses: HashMap<ActionId, usize>,
+    response_uses: HashMap<ResponseId, usize>,
+    response_failures: HashMap<ResponseId, usize>,
+    quest_stages: HashMap<QuestId, QuestStageId>,
+    quest_statuses: HashMap<QuestId, QuestStatus>,
+    score: i32,
+    achievements: BTreeSet<AchievementId>,
+    turns: usize,
+    minutes: u32,
+    timers: Vec<(usize, ActionId)>,
+    events: Vec<ActionId>,
+    party: Vec<CharacterId>,
+    left_behind: BTreeMap<CharacterId, RoomId>,
+    greetings: HashMap<CharacterId, usize>,
+    greeted_here: BTreeSet<CharacterId>,
+    affinity: HashMap<CharacterId, i32>,
+    vars: HashMap<Identifier, i32>,
+    player_name: String,
+    player_pronouns: Pronouns,
+    verbose: bool,
+    log: MessageLog,
+    logged_room: Option<RoomId>,
+    logged_inventory: BTreeSet<ItemId>,
 }
 
 impl GameState {
@@ -24,40 +52,231 @@ impl GameState {
     }
     pub fn new(world: World) -> Self {
         let current_room = *world.title().start_room();
+        let player_name = world
+            .title()
+            .player_name()
+            .as_deref()
+            .unwrap_or(DEFAULT_PLAYER_NAME)
+            .to_string();
+        let player_pronouns = world.title().player_pronouns().clone().unwrap_or_default();
+        let log = MessageLog::new(world.title().log_length().unwrap_or_default());
         Self {
             world,
             current_room,
             inventory: BTreeSet::new(),
             active_room_variants: HashMap::new(),
+            room_visits: HashMap::from([(current_room, 1)]),
+            dialogue_views: HashMap::new(),
+            action_uses: HashMap::new(),
+            response_uses: HashMap::new(),
+            response_failures: HashMap::new(),
+            quest_stages: HashMap::new(),
+            quest_statuses: HashMap::new(),
+            score: 0,
+            achievements: BTreeSet::new(),
+            turns: 0,
+            minutes: 0,
+            timers: Vec::new(),
+            events: Vec::new(),
+            party: Vec::new(),
+            left_behind: BTreeMap::new(),
+            greetings: HashMap::new(),
+            greeted_here: BTreeSet::new(),
+            affinity: HashMap::new(),
+            vars: HashMap::new(),
+            player_name,
+            player_pronouns,
+            verbose: false,
+            log,
+            logged_room: None,
+            logged_inventory: BTreeSet::new(),
         }
     }
     pub fn title(&self) -> &String {
         self.world.title().title()
     }
-    pub fn greeting(&self) -> &String {
-        self.world.title().greeting()
+    pub fn title_art(&self) -> Option<&str> {
+        self.world.title().art().as_deref()
+    }
+    pub fn greeting(&self) -> String {
+        self.render(self.world.title().greeting())
+    }
+    pub fn credits(&self) -> String {
+        self.render(self.world.title().credits())
+    }
+    pub fn status_bar(&self) -> Option<String> {
+        self.world
+            .title()
+            .status_bar()
+            .as_deref()
+            .map(|status| self.render(status))
     }
-    pub fn credits(&self) -> &String {
-        self.world.title().credits()
+    pub fn show_exhausted(&self) -> bool {
+        *self.world.title().show_exhausted()
     }
     pub fn theme(&self) -> Rc<Theme> {
         self.world.theme()
     }
     pub fn language(&self) -> Rc<Language> {
-        self.world.language()
+        let mut language = Language::clone(&self.world.language());
+        for (_, text) in language.strings_mut() {
+            *text = self.render(text);
+        }
+        Rc::new(language)
     }
     pub fn current_room(&self) -> Room<'_, Self> {
         self.current_room.into_proxy(self)
     }
+    pub fn asks_player_name(&self) -> bool {
+        *self.world.title().ask_player_name()
+    }
+    pub fn asks_pronouns(&self) -> bool {
+        *self.world.title().ask_pronouns()
+    }
+    pub fn set_player_name(&mut self, name: &str) {
+        let name = name.trim();
+        if !name.is_empty() {
+            self.player_name = name.to_string();
+        }
+    }
+    pub fn set_player_pronouns(&mut self, pronouns: Pronouns) {
+        self.player_pronouns = pronouns;
+    }
+    pub const fn verbose(&self) -> bool {
+        self.verbose
+    }
+    pub const fn toggle_verbose(&mut self) {
+        self.verbose = !self.verbose;
+    }
+    pub const fn message_log(&self) -> &MessageLog {
+        &self.log
+    }
+    pub fn record(&mut self, heading: &str, text: &str) {
+        self.log.push(heading, text);
+    }
+    pub fn record_changes(&mut self) {
+        if self.logged_room != Some(self.current_room) {
+            let room = self.current_room();
+            let (name, description) = (room.name().to_string(), room.description());
+            self.log.push(&name, &description);
+            self.logged_room = Some(self.current_room);
+        }
+        let language = self.language();
+        let item_name = |id: &ItemId| self.world.item(*id).name.to_string();
+        let lost = self
+            .logged_inventory
+            .difference(&self.inventory)
+            .map(|id| format!("{} {}", language.item_lost(), item_name(id)));
+        let gained = self
+            .inventory
+            .difference(&self.logged_inventory)
+            .map(|id| format!("{} {}", language.item_gained(), item_name(id)));
+        let changes = lost.chain(gained).collect::<Vec<_>>();
+        for change in changes {
+            self.log.push(language.inventory(), &change);
+        }
+        self.logged_inventory = self.inventory.clone();
+    }
     pub fn has_inventory(&self) -> bool {
         !self.inventory.is_empty()
     }
     pub fn inventory(&self) -> Vec<String> {
         self.inventory
             .iter()
-            .map(|i| self.world.item(*i).description.to_string())
+            .map(|i| self.item_description(*i))
+            .collect()
+    }
+    pub fn carried_items(&self) -> Vec<(ItemId, String, Vec<Alias>)> {
+        self.inventory
+            .iter()
+            .map(|i| {
+                let item = self.world.item(*i);
+                (*i, item.name.to_string(), item.aliases.clone())
+            })
+            .collect()
+    }
+    pub fn has_journal(&self) -> bool {
+        !self.quest_statuses.is_empty()
+    }
+    pub fn journal(&self) -> Vec<Quest<'_, Self>> {
+        let mut quests = self.quest_statuses.keys().copied().collect::<Vec<_>>();
+        quests.sort();
+        quests.into_iter().map(|id| id.into_proxy(self)).collect()
+    }
+    pub fn has_score(&self) -> bool {
+        self.score != 0 || self.world.title().max_score().is_some()
+    }
+    pub const fn score(&self) -> i32 {
+        self.score
+    }
+    pub fn max_score(&self) -> Option<i32> {
+        self.world.title().max_score().map(|s| s.points())
+    }
+    pub fn achievements(&self) -> Vec<&AchievementEntity> {
+        self.world
+            .achievements()
+            .filter(|(id, _)| self.achievements.contains(id))
+            .map(|(_, a)| a)
             .collect()
     }
+    pub const fn turns(&self) -> usize {
+        self.turns
+    }
+    pub fn clock(&self) -> Option<ClockTime> {
+        self.world
+            .title()
+            .start_time()
+            .is_some()
+            .then(|| self.time_of_day())
+    }
+    fn time_of_day(&self) -> ClockTime {
+        self.world
+            .title()
+            .start_time()
+            .unwrap_or_default()
+            .after(self.minutes)
+    }
+    fn minutes_per_turn(&self) -> u32 {
+        self.world
+            .title()
+            .minutes_per_turn()
+            .map_or(DEFAULT_MINUTES_PER_TURN, |m| m.value())
+    }
+    pub const fn has_events(&self) -> bool {
+        !self.events.is_empty()
+    }
+    pub fn take_events(&mut self) -> Vec<ActionId> {
+        std::mem::take(&mut self.events)
+    }
+    pub fn has_greeting(&self) -> bool {
+        self.next_greeting().is_some()
+    }
+    pub fn take_greeting(&mut self) -> Option<(CharacterId, DialogueId)> {
+        let (character, dialogue) = self.next_greeting()?;
+        *self.greetings.entry(character).or_default() += 1;
+        self.greeted_here.insert(character);
+        Some((character, dialogue))
+    }
+    fn next_greeting(&self) -> Option<(CharacterId, DialogueId)> {
+        self.characters_in(self.current_room)
+            .into_iter()
+            .filter(|id| !self.greeted_here.contains(id))
+            .find_map(|id| {
+                let character = self.world.character(id);
+                let uses = self.greetings.get(&id).copied().unwrap_or_default();
+                let dialogue = character.greet_dialogue?;
+                (!character.greet_repeat.is_exhausted(uses)
+                    && character
+                        .greet_requires
+                        .iter()
+                        .all(|r| self.requirement_met(r)))
+                .then_some((id, dialogue))
+            })
+    }
+    pub fn item_description(&self, id: ItemId) -> String {
+        let item = self.world.item(id);
+        with_fragments(self, &item.description, &item.fragments)
+    }
     fn requirement_met(&self, requirement: &Requirement) -> bool {
         match requirement {
             Requirement::HasItem(needed_item) => self.inventory.contains(needed_item),
@@ -65,6 +284,23 @@ impl GameState {
             Requirement::RoomVariant(room, variant) => {
                 variant == &self.active_room_variants.get(room).cloned()
             }
+            Requirement::Visited(room) => self.room_visits.contains_key(room),
+            Requirement::Seen(dialogue) => self.dialogue_views.contains_key(dialogue),
+            Requirement::Did(action) => self.action_uses.contains_key(action),
+            Requirement::Chose(response) => self.response_uses.contains_key(response),
+            Requirement::QuestStage(quest, stage) => {
+                self.quest_statuses.contains_key(quest)
+                    && stage == &self.quest_stages.get(quest).cloned()
+            }
+            Requirement::QuestStatus(quest, status) => &self.quest_status(*quest) == status,
+            Requirement::Turns(range) => range.contains(self.turns),
+            Requirement::Time(time) => time.contains(self.time_of_day()),
+            Requirement::InParty(character) => self.party.contains(character),
+            Requirement::Affinity(character, threshold) => {
+                threshold.contains(self.affinity.get(character).copied().unwrap_or_default())
+            }
+            Requirement::PlayerName(name) => self.player_name.eq_ignore_ascii_case(name),
+            Requirement::PlayerPronouns(pronouns) => self.player_pronouns == *pronouns,
         }
     }
     fn action_requirement_met(&self, action: &ActionEntity) -> bool {
@@ -97,11 +333,50 @@ impl GameState {
                     required.push(r);
                 }
             }
+            ChangeQuest(c) => {
+                if let Some(r) = c.required {
+                    required.push(r);
+                }
+            }
+            StartTimer(t) => {
+                if let Some(r) = t.required {
+                    required.push(r);
+                }
+            }
+            ChangeParty(c) => {
+                if let Some(r) = c.required {
+                    required.push(r);
+                }
+            }
+            ChangeVar(c) => {
+                if let Some(r) = c.required {
+                    required.push(r);
+                }
+            }
         }
         required.iter().all(|r| self.inventory.contains(r))
     }
+    fn unlock_achievements(&mut self) {
+        let unlocked = self
+            .world
+            .achievements()
+            .filter(|(id, a)| {
+                !self.achievements.contains(id)
+                    && a.requires.iter().all(|r| self.requirement_met(r))
+            })
+            .map(|(id, a)| {
+                info!("Unlocked achievement {}", a.name);
+                id
+            })
+            .collect::<Vec<_>>();
+        self.achievements.extend(unlocked);
+    }
     fn complete_action(&mut self, action: &ActionEntity) {
         use ActionEntity::*;
+        self.score += action.common().score.points();
+        for (character, amount) in &action.common().affinity {
+            *self.affinity.entry(*character).or_default() += amount;
+        }
         match action {
             ChangeRoom(c) => {
                 if let Some(r) = c.required {
@@ -132,7 +407,7 @@ impl GameState {
                 if let Some(r) = t.required {
                     self.inventory.remove(&r);
                 }
-                self.enter_room(t.room);
+                self.move_to(t.room);
             }
             Sequence(s) => {
                 if let Some(r) = s.required {
@@ -141,24 +416,173 @@ impl GameState {
                 for id in &s.actions {
                     let a = self.world.action(*id).clone();
                     self.complete_action(&a);
+                    *self.action_uses.entry(*id).or_default() += 1;
+                }
+            }
+            ChangeQuest(c) => {
+                if let Some(r) = c.required {
+                    self.inventory.remove(&r);
+                }
+                match (c.status, c.stage) {
+                    (_, Some(stage)) => {
+                        self.quest_stages.insert(c.quest, stage);
+                    }
+                    (QuestStatus::Active, None) => {
+                        self.quest_stages.remove(&c.quest);
+                    }
+                    _ => {}
                 }
+                self.quest_statuses.insert(c.quest, c.status);
             }
+            StartTimer(t) => {
+                if let Some(r) = t.required {
+                    self.inventory.remove(&r);
+                }
+                self.timers.push((self.turns + t.turns, t.action));
+            }
+            ChangeParty(c) => {
+                if let Some(r) = c.required {
+                    self.inventory.remove(&r);
+                }
+                let was_member = self.party.contains(&c.character);
+                self.party.retain(|id| *id != c.character);
+                if c.joins {
+                    self.left_behind.remove(&c.character);
+                    self.party.push(c.character);
+                } else if was_member {
+                    self.left_behind.insert(c.character, self.current_room);
+                }
+            }
+            ChangeVar(c) => {
+                if let Some(r) = c.required {
+                    self.inventory.remove(&r);
+                }
+                for change in &c.changes {
+                    let value = self.vars.entry(change.var().clone()).or_default();
+                    *value = change.apply(*value);
+                }
+            }
+        }
+    }
+    fn placeholder_value(&self, placeholder: &Placeholder) -> String {
+        match placeholder {
+            Placeholder::PlayerName => self.player_name.clone(),
+            Placeholder::PlayerPronoun(word) => self.player_pronouns.word(*word),
+            Placeholder::Var(GameVar::Score) => self.score.to_string(),
+            Placeholder::Var(GameVar::Turns) => self.turns.to_string(),
+            Placeholder::Var(GameVar::Time) => self.time_of_day().to_string(),
+            Placeholder::Var(GameVar::Room) => self.lookup_room(self.current_room).name.clone(),
+            Placeholder::Var(GameVar::Items) => self.inventory.len().to_string(),
+            Placeholder::Var(GameVar::Custom(var)) => {
+                self.vars.get(var).copied().unwrap_or_default().to_string()
+            }
+            Placeholder::Item { item, count } => {
+                self.world
+                    .find_item(item)
+                    .map_or_else(String::new, |(id, item)| {
+                        if *count {
+                            usize::from(self.inventory.contains(&id)).to_string()
+                        } else {
+                            item.name.to_string()
+                        }
+                    })
+            }
+            Placeholder::Character { character, field } => self
+                .world
+                .find_character(character)
+                .map_or_else(String::new, |(id, character)| match field {
+                    CharacterField::Name => character.name.clone(),
+                    CharacterField::Affinity => self
+                        .affinity
+                        .get(&id)
+                        .copied()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CharacterField::Pronoun(word) => character.pronouns.word(*word),
+                }),
         }
     }
+    fn move_to(&mut self, id: RoomId) {
+        self.current_room = id;
+        self.greeted_here.clear();
+        *self.room_visits.entry(id).or_default() += 1;
+    }
+    fn can_do_action(&self, id: ActionId) -> bool {
+        !self.is_action_exhausted(id) && self.action_requirement_met(self.world.action(id))
+    }
+    fn run_action(&mut self, id: ActionId) {
+        let action = self.world.action(id).clone();
+        info!("do_action({action:#?})");
+        self.complete_action(&action);
+        *self.action_uses.entry(id).or_default() += 1;
+    }
+    fn try_action(&mut self, id: ActionId) -> bool {
+        let possible = self.can_do_action(id);
+        if possible {
+            self.run_action(id);
+        }
+        possible
+    }
+    const fn start_turn(&mut self, minutes: u32) {
+        self.turns += 1;
+        // Only the time of day is shown, so wrapping around is harmless
+        self.minutes = self.minutes.wrapping_add(minutes);
+    }
+    fn end_turn(&mut self) {
+        let turn = self.turns;
+        let scheduled = self
+            .world
+            .actions()
+            .filter(|(_, a)| a.common().timing.is_some_and(|t| t.is_due(turn)))
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        let (expired, timers) = self.timers.iter().partition(|(due, _)| *due <= turn);
+        self.timers = timers;
+        let expired = expired.into_iter().map(|(_, id)| id);
+        for id in scheduled.into_iter().chain(expired) {
+            if self.try_action(id) {
+                info!("Turn {turn} fired event {id:?}");
+                self.events.push(id);
+            }
+        }
+        self.unlock_achievements();
+    }
 }
 impl Lookup for GameState {
     fn lookup_action(&self, id: ActionId) -> &ActionEntity {
         self.world.action(id)
     }
     fn lookup_room(&self, id: RoomId) -> &RoomVariantEntity {
-        let variant_id = self.active_room_variants.get(&id).copied();
-        self.world.room(id, variant_id)
+        if let Some(variant_id) = self.active_room_variants.get(&id).copied() {
+            return self.world.room(id, Some(variant_id));
+        }
+        self.world
+            .room_variants(id)
+            .iter()
+            .enumerate()
+            .filter(|(_, room)| {
+                !room.requires.is_empty() && room.requires.iter().all(|r| self.requirement_met(r))
+            })
+            .max_by_key(|(_, room)| (room.priority, room.requires.len()))
+            .map(|(index, room)| {
+                debug!(
+                    "lookup_room({id:?}) chose variant {index} with priority {} and {} requirements met",
+                    room.priority,
+                    room.requires.len()
+                );
+                room
+            })
+            .unwrap_or_else(|| {
+                debug!("lookup_room({id:?}) fell back to the default variant");
+                self.world.room(id, None)
+            })
     }
     fn lookup_dialogue(&self, id: DialogueId) -> &DialogueVariantEntity {
         let variants = &self.world.dialogue(id);
         variants
             .iter()
-            .filter_map(|dialogue| {
+            .enumerate()
+            .filter_map(|(index, dialogue)| {
                 let count = dialogue
                     .requires
                     .iter()
@@ -168,12 +592,18 @@ impl Lookup for GameState {
                 if dialogue.requires.len() != count || count == 0 {
                     None
                 } else {
-                    Some((count, dialogue))
+                    Some(((dialogue.priority, count), index, dialogue))
                 }
             })
-            .max_by_key(|(k, _)| *k)
-            .map(|(_, v)| v)
+            .max_by_key(|(k, _, _)| *k)
+            .map(|((priority, count), index, v)| {
+                debug!(
+                    "lookup_dialogue({id:?}) chose variant {index} with priority {priority} and {count} requirements met"
+                );
+                v
+            })
             .unwrap_or_else(|| {
+                debug!("lookup_dialogue({id:?}) fell back to the default variant");
                 #[allow(clippy::expect_used)]
                 variants
                     .first()
@@ -195,26 +625,123 @@ impl Lookup for GameState {
             })
             .collect()
     }
+    fn filter_fragments(&self, unfiltered: &[FragmentId]) -> Vec<FragmentId> {
+        unfiltered
+            .iter()
+            .filter(|id| {
+                self.world
+                    .fragment(**id)
+                    .requires
+                    .iter()
+                    .all(|r| self.requirement_met(r))
+            })
+            .copied()
+            .collect()
+    }
+    fn is_action_exhausted(&self, id: ActionId) -> bool {
+        let uses = self.action_uses.get(&id).copied().unwrap_or_default();
+        self.world.action(id).common().repeat.is_exhausted(uses)
+    }
+    fn is_response_exhausted(&self, id: ResponseId) -> bool {
+        let uses = self.response_uses.get(&id).copied().unwrap_or_default()
+            + self.response_failures.get(&id).copied().unwrap_or_default();
+        self.world.response(id).repeat.is_exhausted(uses)
+    }
+    fn is_response_chosen(&self, id: ResponseId) -> bool {
+        self.response_uses.contains_key(&id)
+    }
+    fn room_visits(&self, id: RoomId) -> usize {
+        self.room_visits.get(&id).copied().unwrap_or_default()
+    }
+    fn is_verbose(&self) -> bool {
+        self.verbose
+    }
     fn lookup_character(&self, id: CharacterId) -> &CharacterEntity {
         self.world.character(id)
     }
     fn lookup_response(&self, id: ResponseId) -> &ResponseEntity {
         self.world.response(id)
     }
+    fn lookup_fragment(&self, id: FragmentId) -> &FragmentEntity {
+        self.world.fragment(id)
+    }
+    fn lookup_quest(&self, id: QuestId) -> &QuestStageEntity {
+        let stage_id = self.quest_stages.get(&id).copied();
+        self.world.quest(id, stage_id)
+    }
+    fn quest_status(&self, id: QuestId) -> QuestStatus {
+        self.quest_statuses.get(&id).copied().unwrap_or_default()
+    }
+    fn render(&self, text: &str) -> String {
+        // Values like the player's name are shown as typed, not read as markup
+        render_template(text, |placeholder| {
+            escape_markup(&self.placeholder_value(placeholder))
+        })
+    }
+    fn characters_in(&self, room: RoomId) -> Vec<CharacterId> {
+        let listed = self
+            .lookup_room(room)
+            .characters
+            .iter()
+            .copied()
+            .filter(|id| !self.party.contains(id) && !self.left_behind.contains_key(id));
+        let left_behind = self
+            .left_behind
+            .iter()
+            .filter(|(_, r)| **r == room)
+            .map(|(id, _)| *id);
+        let party = self
+            .party
+            .iter()
+            .copied()
+            .filter(|_| room == self.current_room);
+        listed.chain(left_behind).chain(party).collect()
+    }
 }
 impl Update for GameState {
     fn enter_room(&mut self, id: RoomId) {
-        self.current_room = id;
+        let minutes = self
+            .lookup_room(self.current_room)
+            .travel_time(id)
+            .map_or_else(|| self.minutes_per_turn(), |m| m.value());
+        self.start_turn(minutes);
+        self.move_to(id);
+        self.end_turn();
     }
     fn do_action(&mut self, id: ActionId) -> bool {
-        let action = self.world.action(id).clone();
-        info!("do_action({action:#?})");
-        if self.action_requirement_met(&action) {
-            self.complete_action(&action);
-            true
-        } else {
-            false
+        // A failed action takes no time, so timers and the clock stay put
+        if !self.can_do_action(id) {
+            info!("do_action({id:?}) failed");
+            return false;
+        }
+        let minutes = self
+            .world
+            .action(id)
+            .common()
+            .duration
+            .map_or_else(|| self.minutes_per_turn(), |m| m.value());
+        self.start_turn(minutes);
+        self.run_action(id);
+        self.end_turn();
+        true
+    }
+    fn choose_response(&mut self, id: ResponseId) {
+        self.start_turn(self.minutes_per_turn());
+        *self.response_uses.entry(id).or_default() += 1;
+        self.score += self.world.response(id).score.points();
+        for (character, amount) in &self.world.response(id).affinity {
+            *self.affinity.entry(*character).or_default() += amount;
         }
+        self.end_turn();
+    }
+    fn fail_response(&mut self, id: ResponseId) {
+        self.start_turn(self.minutes_per_turn());
+        *self.response_failures.entry(id).or_default() += 1;
+        self.end_turn();
+    }
+    fn view_dialogue(&mut self, id: DialogueId) {
+        *self.dialogue_views.entry(id).or_default() += 1;
+        self.unlock_achievements();
     }
 }
 
@@ -227,6 +754,7 @@ mod test {
     use rstest::*;
 
     use super::*;
+    use crate::core::strip_markup;
 
     #[fixture]
     fn game() -> GameState {
@@ -240,11 +768,17 @@ mod test {
                 text: "Hiya stranger!".into(),
                 requires: vec![],
                 responses: vec![0.into(), 1.into()],
+                fragments: vec![],
+                priority: 0,
+                speaker: None,
             },
             DialogueVariantEntity {
                 text: "Who goes there?".into(),
                 requires: vec![Requirement::HasItem(1.into())],
                 responses: vec![0.into(), 1.into()],
+                fragments: vec![],
+                priority: 0,
+                speaker: None,
             },
         ]];
         let responses = vec![
@@ -253,12 +787,24 @@ mod test {
                 requires: vec![],
                 leads_to: None,
                 triggers: None,
+                repeat: Repeat::Always,
+                score: Score::default(),
+                affinity: vec![],
+                answer: None,
+                fail_leads_to: None,
+                fail_triggers: None,
             },
             ResponseEntity {
                 text: "I have the ring.".into(),
                 requires: vec![Requirement::HasItem(1.into())],
                 leads_to: None,
                 triggers: None,
+                repeat: Repeat::Always,
+                score: Score::default(),
+                affinity: vec![],
+                answer: None,
+                fail_leads_to: None,
+                fail_triggers: None,
             },
         ];
         make_game().dialogues(dialogues).responses(responses).call()
@@ -270,6 +816,9 @@ mod test {
             text: "Hiya stranger!".into(),
             requires: vec![],
             responses: vec![0.into(), 1.into()],
+            fragments: vec![],
+            priority: 0,
+            speaker: None,
         }]];
         let responses = vec![
             ResponseEntity {
@@ -277,12 +826,24 @@ mod test {
                 requires: vec![],
                 leads_to: None,
                 triggers: None,
+                repeat: Repeat::Always,
+                score: Score::default(),
+                affinity: vec![],
+                answer: None,
+                fail_leads_to: None,
+                fail_triggers: None,
             },
             ResponseEntity {
                 text: "I have the ring.".into(),
                 requires: vec![Requirement::HasItem(1.into())],
                 leads_to: None,
                 triggers: None,
+                repeat: Repeat::Always,
+                score: Score::default(),
+                affinity: vec![],
+                answer: None,
+                fail_leads_to: None,
+                fail_triggers: None,
             },
         ];
         make_game().dialogues(dialogues).responses(responses).call()
@@ -296,6 +857,10 @@ mod test {
         dialogues: Option<Vec<DialogueEntity>>,
         characters: Option<Vec<CharacterEntity>>,
         responses: Option<Vec<ResponseEntity>>,
+        fragments: Option<Vec<FragmentEntity>>,
+        quests: Option<Vec<QuestEntity>>,
+        achievements: Option<Vec<AchievementEntity>>,
+        start_time: Option<ClockTime>,
     ) -> GameState {
         let world = World::builder()
             .title(
@@ -304,6 +869,7 @@ mod test {
                     .greeting("".into())
                     .credits("".into())
                     .start_room(0usize.into())
+                    .maybe_start_time(start_time)
                     .build(),
             )
             .language(Language::default())
@@ -313,10 +879,14 @@ mod test {
                     Item {
                         name: "key".parse().unwrap(),
                         description: "a key".into(),
+                        fragments: vec![],
+                        aliases: vec![],
                     },
                     Item {
                         name: "ring".parse().unwrap(),
                         description: "a ring".into(),
+                        fragments: vec![],
+                        aliases: vec![],
                     },
                 ]
             }))
@@ -327,30 +897,57 @@ mod test {
                         RoomVariantEntity {
                             name: "WoodShed".into(),
                             description: "A Shed".into(),
+                            first_description: None,
+                            short_description: None,
+                            art: None,
                             characters: vec![],
                             exits: IndexMap::new(),
+                            exit_aliases: IndexMap::new(),
+                            travel_times: IndexMap::new(),
                             actions: vec![],
+                            fragments: vec![],
+                            requires: vec![],
+                            priority: 0,
                         },
                         RoomVariantEntity {
                             name: "WoodShed".into(),
                             description: "A Shed variant".into(),
+                            first_description: None,
+                            short_description: None,
+                            art: None,
                             characters: vec![],
                             exits: IndexMap::new(),
+                            exit_aliases: IndexMap::new(),
+                            travel_times: IndexMap::new(),
                             actions: vec![],
+                            fragments: vec![],
+                            requires: vec![],
+                            priority: 0,
                         },
                     ],
                     vec![RoomVariantEntity {
                         name: "Field".into(),
                         description: "An open field".into(),
+                        first_description: None,
+                        short_description: None,
+                        art: None,
                         characters: vec![],
                         exits: IndexMap::new(),
+                        exit_aliases: IndexMap::new(),
+                        travel_times: IndexMap::new(),
                         actions: vec![],
+                        fragments: vec![],
+                        requires: vec![],
+                        priority: 0,
                     }],
                 ]
             }))
             .dialogues(dialogues.unwrap_or_default())
             .characters(characters.unwrap_or_default())
             .responses(responses.unwrap_or_default())
+            .fragments(fragments.unwrap_or_default())
+            .quests(quests.unwrap_or_default())
+            .achievements(achievements.unwrap_or_default())
             .build();
 
         let mut game = GameState::new(world);
@@ -365,6 +962,11 @@ mod test {
     #[case::does_not_have_item_false(Requirement::DoesNotHave(0.into()), false)]
     #[case::room_variant_true(Requirement::RoomVariant(0.into(), None), true)]
     #[case::room_variant_false(Requirement::RoomVariant(0.into(), Some(1.into())), false)]
+    #[case::visited_start_room(Requirement::Visited(0.into()), true)]
+    #[case::visited_false(Requirement::Visited(1.into()), false)]
+    #[case::seen_false(Requirement::Seen(0.into()), false)]
+    #[case::did_false(Requirement::Did(0.into()), false)]
+    #[case::chose_false(Requirement::Chose(0.into()), false)]
     fn requirement_met(game: GameState, #[case] req: Requirement, #[case] expected: bool) {
         assert_eq!(
             game.requirement_met(&req),
@@ -389,6 +991,42 @@ mod test {
         );
     }
 
+    #[rstest]
+    fn requirement_met_history(#[from(dialogue_game)] mut game: GameState) {
+        game.enter_room(1.into());
+        game.view_dialogue(0.into());
+        game.choose_response(0.into());
+        for req in [
+            Requirement::Visited(1.into()),
+            Requirement::Seen(0.into()),
+            Requirement::Chose(0.into()),
+        ] {
+            assert!(game.requirement_met(&req), "Failed for requirement {req:?}");
+        }
+    }
+
+    #[rstest]
+    fn sequence_marks_all_actions_done() {
+        let sequence = ActionEntity::Sequence(Sequence {
+            name: "do_multiple".parse().unwrap(),
+            description: "".into(),
+            common: ActionCommon::default(),
+            actions: vec![ActionId::from(0)],
+            required: None,
+        });
+        let give_ring = ActionEntity::GiveItem(GiveItem {
+            name: "give_ring".parse().unwrap(),
+            description: "".into(),
+            common: ActionCommon::default(),
+            items: vec![1.into()],
+            required: None,
+        });
+        let mut game = make_game().actions(vec![give_ring, sequence]).call();
+        assert!(game.do_action(1.into()));
+        assert!(game.requirement_met(&Requirement::Did(0.into())));
+        assert!(game.requirement_met(&Requirement::Did(1.into())));
+    }
+
     #[rstest]
     #[case::required_found(Some(0.into()), true)]
     #[case::required_not_found(Some(1.into()), false)]
@@ -401,6 +1039,7 @@ mod test {
         let action = ActionEntity::GiveItem(GiveItem {
             name: "give_ring".parse().unwrap(),
             description: "Give the ring".into(),
+            common: ActionCommon::default(),
             items: vec![1.into()],
             required,
         });
@@ -413,6 +1052,7 @@ mod test {
         ActionEntity::GiveItem(GiveItem {
             name: "give_ring".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             items: vec![1.into()],
             required: Some(0.into()),
         }),
@@ -423,6 +1063,7 @@ mod test {
         ActionEntity::ReplaceItem(ReplaceItem {
             name: "replace_key_with_ring".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             original: 0.into(),
             replacement: 1.into(),
         }),
@@ -433,6 +1074,7 @@ mod test {
         ActionEntity::TakeItem(TakeItem {
             name: "take_key".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             items: vec![0.into()],
         }),
         vec![],
@@ -465,6 +1107,7 @@ mod test {
         let action = ActionEntity::ChangeRoom(ChangeRoom {
             name: "close_door".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             room: 0.into(),
             variant: Some(1.into()),
             required: Some(0.into()),
@@ -483,6 +1126,7 @@ mod test {
         let action = ActionEntity::Teleport(Teleport {
             name: "beam_me_up".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             room: 1.into(),
             required: Some(0.into()),
         });
@@ -496,17 +1140,20 @@ mod test {
         let sequence = ActionEntity::Sequence(Sequence {
             name: "do_multiple".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             actions: vec![ActionId::from(0), ActionId::from(1)],
             required: None,
         });
         let take_key = ActionEntity::TakeItem(TakeItem {
             name: "take_key".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             items: vec![0.into()],
         });
         let give_ring = ActionEntity::GiveItem(GiveItem {
             name: "give_ring".parse().unwrap(),
             description: "".into(),
+            common: ActionCommon::default(),
             items: vec![1.into()],
             required: None,
         });
@@ -549,4 +1196,1008 @@ mod test {
         let filtered = response_game.filter_responses(&[0.into(), 1.into()]);
         assert_eq!(filtered.len(), 2, "Both responses should be allowed");
     }
+
+    #[rstest]
+    #[case::once(Repeat::Times(1), vec![true, false])]
+    #[case::twice(Repeat::Times(2), vec![true, true, false])]
+    #[case::always(Repeat::Always, vec![true, true, true])]
+    fn do_action_respects_repeat(#[case] repeat: Repeat, #[case] expected: Vec<bool>) {
+        let action = ActionEntity::GiveItem(GiveItem {
+            name: "give_ring".parse().unwrap(),
+            description: "".into(),
+            common: ActionCommon {
+                repeat,
+                ..ActionCommon::default()
+            },
+            items: vec![1.into()],
+            required: None,
+        });
+        let mut game = make_game().actions(vec![action]).call();
+        for (attempt, success) in expected.into_iter().enumerate() {
+            assert_eq!(
+                game.do_action(0.into()),
+                success,
+                "Unexpected result on attempt {attempt}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn failed_actions_take_no_time() {
+        let give_ring = ActionEntity::GiveItem(GiveItem {
+            name: "give_ring".parse().unwrap(),
+            description: "".into(),
+            common: ActionCommon {
+                repeat: Repeat::Times(1),
+                ..ActionCommon::default()
+            },
+            items: vec![],
+            required: Some(1.into()),
+        });
+        let chime = ActionEntity::GiveItem(GiveItem {
+            name: "chime".into(),
+            description: "".into(),
+            common: ActionCommon {
+                timing: Timing::new(None, Some(1)),
+                ..ActionCommon::default()
+            },
+            items: vec![],
+            required: None,
+        });
+        let mut game = make_game()
+            .actions(vec![give_ring, chime])
+            .start_time("08:00".parse().unwrap())
+            .call();
+
+        assert!(!game.do_action(0.into()), "Item is not held yet");
+        assert_eq!(game.turns(), 0);
+        assert_eq!(game.clock().unwrap().to_string(), "08:00");
+        assert!(!game.has_events());
+
+        game.inventory.insert(1.into());
+        assert!(game.do_action(0.into()));
+        assert_eq!(game.turns(), 1);
+        assert_eq!(game.clock().unwrap().to_string(), "08:01");
+        assert_eq!(game.take_events(), vec![ActionId::from(1)]);
+
+        game.inventory.insert(1.into());
+        assert!(!game.do_action(0.into()), "Repeat is exhausted");
+        assert_eq!(game.turns(), 1);
+        assert_eq!(game.clock().unwrap().to_string(), "08:01");
+        assert!(!game.has_events());
+    }
+
+    #[rstest]
+    fn room_actions_exclude_exhausted_actions() {
+        let turn_valve = ActionEntity::ChangeRoom(ChangeRoom {
+            name: "turn_valve".parse().unwrap(),
+            description: "".into(),
+            common: ActionCommon {
+                repeat: Repeat::Times(1),
+                ..ActionCommon::default()
+            },
+            room: 1.into(),
+            variant: None,
+            required: None,
+        });
+        let rooms = vec![vec![RoomVariantEntity {
+            name: "Basement".into(),
+            description: "A flooded basement".into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters: vec![],
+            exits: IndexMap::new(),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::new(),
+            actions: vec![0.into()],
+            fragments: vec![],
+            requires: vec![],
+            priority: 0,
+        }]];
+        let mut game = make_game().actions(vec![turn_valve]).rooms(rooms).call();
+        assert_eq!(game.current_room().actions().count(), 1);
+        assert!(game.do_action(0.into()));
+        assert_eq!(game.current_room().actions().count(), 0);
+        assert_eq!(game.current_room().exhausted_actions().count(), 1);
+    }
+
+    #[rstest]
+    fn dialogue_responses_remember_being_chosen(mut response_game: GameState) {
+        response_game.inventory.insert(1.into());
+        let chosen = |game: &GameState| {
+            DialogueId::from(0)
+                .into_proxy(game)
+                .responses()
+                .map(|r| r.was_chosen())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(chosen(&response_game), vec![false, false]);
+        response_game.choose_response(1.into());
+        assert_eq!(chosen(&response_game), vec![false, true]);
+    }
+
+    #[rstest]
+    fn dialogue_responses_exclude_exhausted_responses() {
+        let mut game = make_game()
+            .dialogues(vec![vec![DialogueVariantEntity {
+                text: "Hiya stranger!".into(),
+                requires: vec![],
+                responses: vec![0.into()],
+                fragments: vec![],
+                priority: 0,
+                speaker: None,
+            }]])
+            .responses(vec![ResponseEntity {
+                text: "Hello!".into(),
+                requires: vec![],
+                leads_to: None,
+                triggers: None,
+                repeat: Repeat::Times(1),
+                score: Score::default(),
+                affinity: vec![],
+                answer: None,
+                fail_leads_to: None,
+                fail_triggers: None,
+            }])
+            .call();
+        let dialogue = DialogueId::from(0).into_proxy(&game);
+        assert_eq!(dialogue.responses().count(), 1);
+        game.choose_response(0.into());
+        let dialogue = DialogueId::from(0).into_proxy(&game);
+        assert_eq!(dialogue.responses().count(), 0);
+        assert_eq!(dialogue.exhausted_responses().count(), 1);
+    }
+
+    #[rstest]
+    fn room_description_depends_on_visits() {
+        let rooms = vec![
+            vec![RoomVariantEntity {
+                name: "Hall".into(),
+                description: "A long hall.".into(),
+                first_description: Some("A long, echoing hall.".into()),
+                short_description: Some("The hall.".into()),
+                art: None,
+                characters: vec![],
+                exits: IndexMap::new(),
+                exit_aliases: IndexMap::new(),
+                travel_times: IndexMap::new(),
+                actions: vec![],
+                fragments: vec![],
+                requires: vec![],
+                priority: 0,
+            }],
+            vec![RoomVariantEntity {
+                name: "Field".into(),
+                description: "An open field".into(),
+                first_description: None,
+                short_description: None,
+                art: None,
+                characters: vec![],
+                exits: IndexMap::new(),
+                exit_aliases: IndexMap::new(),
+                travel_times: IndexMap::new(),
+                actions: vec![],
+                fragments: vec![],
+                requires: vec![],
+                priority: 0,
+            }],
+        ];
+        let mut game = make_game().rooms(rooms).call();
+        assert_eq!(game.current_room().description(), "A long, echoing hall.");
+        game.enter_room(1.into());
+        game.enter_room(0.into());
+        assert_eq!(game.current_room().description(), "The hall.");
+        game.toggle_verbose();
+        assert_eq!(game.current_room().description(), "A long hall.");
+    }
+
+    #[rstest]
+    fn description_fragments_follow_requirements() {
+        let rooms = vec![vec![RoomVariantEntity {
+            name: "Study".into(),
+            description: "A quiet study.".into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters: vec![],
+            exits: IndexMap::new(),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::new(),
+            actions: vec![],
+            fragments: vec![0.into(), 1.into()],
+            requires: vec![],
+            priority: 0,
+        }]];
+        let fragments = vec![
+            FragmentEntity {
+                text: "A lamp glows.".into(),
+                requires: vec![Requirement::HasItem(1.into())],
+            },
+            FragmentEntity {
+                text: "The window is shut.".into(),
+                requires: vec![],
+            },
+        ];
+        let mut game = make_game().rooms(rooms).fragments(fragments).call();
+        assert_eq!(
+            game.current_room().description(),
+            "A quiet study. The window is shut."
+        );
+        game.inventory.insert(1.into());
+        assert_eq!(
+            game.current_room().description(),
+            "A quiet study. A lamp glows. The window is shut."
+        );
+    }
+
+    #[rstest]
+    fn lookup_dialogue_prefers_priority_over_requirement_count() {
+        let dialogues = vec![vec![
+            DialogueVariantEntity {
+                text: "Hiya stranger!".into(),
+                requires: vec![],
+                responses: vec![],
+                fragments: vec![],
+                priority: 0,
+                speaker: None,
+            },
+            DialogueVariantEntity {
+                text: "Who goes there?".into(),
+                requires: vec![
+                    Requirement::HasItem(0.into()),
+                    Requirement::Visited(0.into()),
+                ],
+                responses: vec![],
+                fragments: vec![],
+                priority: 0,
+                speaker: None,
+            },
+            DialogueVariantEntity {
+                text: "Nice key!".into(),
+                requires: vec![Requirement::HasItem(0.into())],
+                responses: vec![],
+                fragments: vec![],
+                priority: 1,
+                speaker: None,
+            },
+        ]];
+        let game = make_game().dialogues(dialogues).call();
+        let dialogue = game.lookup_dialogue(0usize.into());
+        assert_eq!(dialogue.text, "Nice key!");
+    }
+
+    #[rstest]
+    fn lookup_room_prefers_priority_over_requirement_count() {
+        let room =
+            |description: &str, requires: Vec<Requirement>, priority: i32| RoomVariantEntity {
+                name: "Garden".into(),
+                description: description.into(),
+                first_description: None,
+                short_description: None,
+                art: None,
+                characters: vec![],
+                exits: IndexMap::new(),
+                exit_aliases: IndexMap::new(),
+                travel_times: IndexMap::new(),
+                actions: vec![],
+                fragments: vec![],
+                requires,
+                priority,
+            };
+        let night = || Requirement::Time("night".parse().unwrap());
+        let rooms = vec![vec![
+            room("Bees hum among the roses.", vec![], 0),
+            room(
+                "Crickets chirp by the pond.",
+                vec![night(), Requirement::Visited(0.into())],
+                0,
+            ),
+            room("An owl hoots overhead.", vec![night()], 1),
+        ]];
+        let game = make_game()
+            .rooms(rooms)
+            .start_time("23:00".parse().unwrap())
+            .call();
+        assert_eq!(game.current_room().description(), "An owl hoots overhead.");
+    }
+
+    #[rstest]
+    fn change_quest_tracks_stage_and_status() {
+        let change_quest = |name: &str, stage: Option<QuestStageId>, status: QuestStatus| {
+            ActionEntity::ChangeQuest(ChangeQuest {
+                name: name.into(),
+                description: "".into(),
+                common: ActionCommon::default(),
+                required: None,
+                quest: 0.into(),
+                stage,
+                status,
+            })
+        };
+        let quests = vec![vec![
+            QuestStageEntity {
+                name: "Lost Ring".into(),
+                description: "Find the ring.".into(),
+            },
+            QuestStageEntity {
+                name: "Lost Ring".into(),
+                description: "Return the ring.".into(),
+            },
+        ]];
+        let actions = vec![
+            change_quest("start", None, QuestStatus::Active),
+            change_quest("advance", Some(1.into()), QuestStatus::Active),
+            change_quest("complete", None, QuestStatus::Completed),
+        ];
+        let mut game = make_game().actions(actions).quests(quests).call();
+        assert!(!game.has_journal());
+        assert!(game.requirement_met(&Requirement::QuestStatus(0.into(), QuestStatus::NotStarted)));
+        assert!(!game.requirement_met(&Requirement::QuestStage(0.into(), None)));
+
+        game.do_action(0.into());
+        assert!(game.has_journal());
+        assert_eq!(game.lookup_quest(0.into()).description, "Find the ring.");
+        assert!(game.requirement_met(&Requirement::QuestStage(0.into(), None)));
+        assert!(game.requirement_met(&Requirement::QuestStatus(0.into(), QuestStatus::Active)));
+
+        game.do_action(1.into());
+        assert_eq!(game.lookup_quest(0.into()).description, "Return the ring.");
+        assert!(game.requirement_met(&Requirement::QuestStage(0.into(), Some(1.into()))));
+
+        game.do_action(2.into());
+        assert_eq!(game.lookup_quest(0.into()).description, "Return the ring.");
+        assert_eq!(game.quest_status(0.into()), QuestStatus::Completed);
+        assert_eq!(game.journal().len(), 1);
+    }
+
+    #[rstest]
+    fn score_counts_actions_and_chosen_responses() {
+        let give_item = |name: &str, score: &str| {
+            ActionEntity::GiveItem(GiveItem {
+                name: name.into(),
+                description: "".into(),
+                common: ActionCommon {
+                    score: score.parse().unwrap(),
+                    ..ActionCommon::default()
+                },
+                items: vec![],
+                required: None,
+            })
+        };
+        let sequence = ActionEntity::Sequence(Sequence {
+            name: "both".into(),
+            description: "".into(),
+            common: ActionCommon::default(),
+            actions: vec![0.into(), 1.into()],
+            required: None,
+        });
+        let responses = vec![ResponseEntity {
+            text: "Swordfish".into(),
+            requires: vec![],
+            leads_to: None,
+            triggers: None,
+            repeat: Repeat::Always,
+            score: "+5".parse().unwrap(),
+            affinity: vec![],
+            answer: None,
+            fail_leads_to: None,
+            fail_triggers: None,
+        }];
+        let actions = vec![give_item("find", "+10"), give_item("lose", "-3"), sequence];
+        let mut game = make_game().actions(actions).responses(responses).call();
+        assert!(!game.has_score());
+
+        game.do_action(0.into());
+        assert_eq!(game.score(), 10);
+        game.do_action(2.into());
+        assert_eq!(game.score(), 17);
+        game.fail_response(0.into());
+        assert_eq!(game.score(), 17);
+        game.choose_response(0.into());
+        assert_eq!(game.score(), 22);
+        assert!(game.has_score());
+    }
+
+    #[rstest]
+    fn wrong_answers_use_up_guesses_without_choosing() {
+        let responses = vec![ResponseEntity {
+            text: "Guess the riddle.".into(),
+            requires: vec![],
+            leads_to: None,
+            triggers: None,
+            repeat: "2".parse().unwrap(),
+            score: Score::default(),
+            affinity: vec![],
+            answer: Some(Answer::new(["echo"], None)),
+            fail_leads_to: None,
+            fail_triggers: None,
+        }];
+        let mut game = make_game().responses(responses).call();
+        let riddle = Requirement::Chose(0.into());
+
+        game.fail_response(0.into());
+        assert!(!game.is_response_chosen(0.into()));
+        assert!(!game.requirement_met(&riddle));
+        assert!(!game.is_response_exhausted(0.into()));
+        game.fail_response(0.into());
+        assert!(game.is_response_exhausted(0.into()));
+        assert!(!game.requirement_met(&riddle));
+    }
+
+    #[rstest]
+    fn achievements_stay_unlocked() {
+        let achievements = vec![
+            AchievementEntity {
+                name: "Ring Bearer".into(),
+                description: "Held the ring.".into(),
+                requires: vec![Requirement::HasItem(1.into())],
+            },
+            AchievementEntity {
+                name: "Explorer".into(),
+                description: "Found the field.".into(),
+                requires: vec![Requirement::Visited(1.into())],
+            },
+        ];
+        let actions = vec![
+            ActionEntity::GiveItem(GiveItem {
+                name: "take_ring".into(),
+                description: "".into(),
+                common: ActionCommon::default(),
+                items: vec![1.into()],
+                required: None,
+            }),
+            ActionEntity::TakeItem(TakeItem {
+                name: "drop_ring".into(),
+                description: "".into(),
+                common: ActionCommon::default(),
+                items: vec![1.into()],
+            }),
+        ];
+        let mut game = make_game()
+            .actions(actions)
+            .achievements(achievements)
+            .call();
+        assert!(game.achievements().is_empty());
+
+        game.do_action(0.into());
+        game.do_action(1.into());
+        let names = |game: &GameState| {
+            game.achievements()
+                .iter()
+                .map(|a| a.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&game), vec!["Ring Bearer"]);
+
+        game.enter_room(1.into());
+        assert_eq!(names(&game), vec!["Ring Bearer", "Explorer"]);
+    }
+
+    #[rstest]
+    fn turns_fire_scheduled_actions_and_timers() {
+        let chime = ActionEntity::GiveItem(GiveItem {
+            name: "chime".into(),
+            description: "The clock chimes.".into(),
+            common: ActionCommon {
+                timing: Timing::new(None, Some(2)),
+                ..ActionCommon::default()
+            },
+            items: vec![],
+            required: None,
+        });
+        let light_candle = ActionEntity::StartTimer(StartTimer {
+            name: "light_candle".into(),
+            description: "You light the candle.".into(),
+            common: ActionCommon::default(),
+            required: None,
+            action: 2.into(),
+            turns: 3,
+        });
+        let burn_out = ActionEntity::GiveItem(GiveItem {
+            name: "burn_out".into(),
+            description: "The candle burns out.".into(),
+            common: ActionCommon::default(),
+            items: vec![1.into()],
+            required: None,
+        });
+        let mut game = make_game()
+            .actions(vec![chime, light_candle, burn_out])
+            .call();
+        assert!(game.requirement_met(&Requirement::Turns("0".parse().unwrap())));
+
+        game.do_action(1.into());
+        assert_eq!(game.turns(), 1);
+        assert!(!game.has_events());
+
+        game.enter_room(1.into());
+        assert_eq!(game.take_events(), vec![ActionId::from(0)]);
+
+        game.enter_room(0.into());
+        game.enter_room(1.into());
+        assert_eq!(game.turns(), 4);
+        assert_eq!(
+            game.take_events(),
+            vec![ActionId::from(0), ActionId::from(2)]
+        );
+        assert!(game.inventory.contains(&1.into()));
+        assert!(game.requirement_met(&Requirement::Turns("3..".parse().unwrap())));
+        assert!(!game.requirement_met(&Requirement::Turns("..3".parse().unwrap())));
+    }
+
+    #[rstest]
+    fn clock_advances_with_travel_and_durations() {
+        let room = |name: &str, exit: usize, minutes: &str| RoomVariantEntity {
+            name: name.into(),
+            description: "".into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters: vec![],
+            exits: IndexMap::from([("out".parse().unwrap(), exit.into())]),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::from([("out".parse().unwrap(), minutes.parse().unwrap())]),
+            actions: vec![],
+            fragments: vec![],
+            requires: vec![],
+            priority: 0,
+        };
+        let wait = ActionEntity::GiveItem(GiveItem {
+            name: "wait".into(),
+            description: "".into(),
+            common: ActionCommon {
+                duration: Some("15".parse().unwrap()),
+                ..ActionCommon::default()
+            },
+            items: vec![],
+            required: None,
+        });
+        let mut game = make_game()
+            .rooms(vec![
+                vec![room("Hall", 1, "30")],
+                vec![room("Yard", 0, "5")],
+            ])
+            .actions(vec![wait])
+            .start_time("21:10".parse().unwrap())
+            .call();
+        let evening = Requirement::Time("evening".parse().unwrap());
+        let night = Requirement::Time("night".parse().unwrap());
+        assert_eq!(game.clock().unwrap().to_string(), "21:10");
+
+        game.enter_room(1.into());
+        assert_eq!(game.clock().unwrap().to_string(), "21:40");
+        assert!(game.requirement_met(&evening));
+
+        game.do_action(0.into());
+        assert_eq!(game.clock().unwrap().to_string(), "21:55");
+
+        game.enter_room(0.into());
+        game.enter_room(0.into());
+        assert_eq!(game.clock().unwrap().to_string(), "22:01");
+        assert!(game.requirement_met(&night));
+        assert!(!game.requirement_met(&evening));
+    }
+
+    #[rstest]
+    fn clock_wraps_instead_of_overflowing(mut game: GameState) {
+        game.minutes = u32::MAX;
+        game.enter_room(1.into());
+        assert_eq!(game.minutes, 0);
+    }
+
+    #[rstest]
+    fn clock_is_hidden_without_start_time(mut game: GameState) {
+        assert_eq!(game.clock(), None);
+        game.enter_room(1.into());
+        assert!(game.requirement_met(&Requirement::Time("00:00..00:02".parse().unwrap())));
+    }
+
+    #[rstest]
+    fn room_variant_follows_time_of_day() {
+        let room = |description: &str, requires: Vec<Requirement>| RoomVariantEntity {
+            name: "Garden".into(),
+            description: description.into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters: vec![],
+            exits: IndexMap::new(),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::new(),
+            actions: vec![],
+            fragments: vec![],
+            requires,
+            priority: 0,
+        };
+        let night = Requirement::Time("night".parse().unwrap());
+        let mut game = make_game()
+            .rooms(vec![vec![
+                room("Bees hum among the roses.", vec![]),
+                room("Crickets chirp in the dark.", vec![night]),
+            ]])
+            .start_time("21:59".parse().unwrap())
+            .call();
+        assert_eq!(
+            game.current_room().description(),
+            "Bees hum among the roses."
+        );
+        game.enter_room(0.into());
+        assert_eq!(
+            game.current_room().description(),
+            "Crickets chirp in the dark."
+        );
+        game.active_room_variants.insert(0.into(), 0.into());
+        assert_eq!(
+            game.current_room().description(),
+            "Bees hum among the roses."
+        );
+    }
+    #[rstest]
+    fn party_members_follow_the_player() {
+        let room = |name: &str, characters: Vec<CharacterId>| RoomVariantEntity {
+            name: name.into(),
+            description: "".into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters,
+            exits: IndexMap::new(),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::new(),
+            actions: vec![],
+            fragments: vec![],
+            requires: vec![],
+            priority: 0,
+        };
+        let change_party = |name: &str, joins| {
+            ActionEntity::ChangeParty(ChangeParty {
+                name: name.into(),
+                description: "".into(),
+                common: ActionCommon::default(),
+                required: None,
+                character: 0.into(),
+                joins,
+            })
+        };
+        let mut game = make_game()
+            .rooms(vec![
+                vec![room("Camp", vec![0.into(), 1.into()])],
+                vec![room("Forest", vec![])],
+            ])
+            .actions(vec![
+                change_party("recruit", true),
+                change_party("dismiss", false),
+            ])
+            .call();
+        let in_party = Requirement::InParty(0.into());
+        assert!(!game.requirement_met(&in_party));
+
+        game.do_action(0.into());
+        assert!(game.requirement_met(&in_party));
+        assert_eq!(game.characters_in(0.into()), vec![1.into(), 0.into()]);
+
+        game.enter_room(1.into());
+        assert_eq!(game.characters_in(0.into()), vec![CharacterId::from(1)]);
+        assert_eq!(game.characters_in(1.into()), vec![CharacterId::from(0)]);
+
+        game.do_action(1.into());
+        assert!(!game.requirement_met(&in_party));
+        game.enter_room(0.into());
+        assert_eq!(game.characters_in(0.into()), vec![CharacterId::from(1)]);
+        assert_eq!(game.characters_in(1.into()), vec![CharacterId::from(0)]);
+    }
+    #[rstest]
+    fn leaving_the_party_needs_a_member() {
+        let room = |name: &str, characters: Vec<CharacterId>| RoomVariantEntity {
+            name: name.into(),
+            description: "".into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters,
+            exits: IndexMap::new(),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::new(),
+            actions: vec![],
+            fragments: vec![],
+            requires: vec![],
+            priority: 0,
+        };
+        let dismiss = ActionEntity::ChangeParty(ChangeParty {
+            name: "dismiss".into(),
+            description: "".into(),
+            common: ActionCommon::default(),
+            required: None,
+            character: 0.into(),
+            joins: false,
+        });
+        let mut game = make_game()
+            .rooms(vec![
+                vec![room("Camp", vec![0.into()])],
+                vec![room("Forest", vec![])],
+            ])
+            .actions(vec![dismiss])
+            .call();
+
+        game.enter_room(1.into());
+        game.do_action(0.into());
+        assert_eq!(game.characters_in(0.into()), vec![CharacterId::from(0)]);
+        assert!(game.characters_in(1.into()).is_empty());
+    }
+    #[rstest]
+    #[case::once(Repeat::Times(1), vec![true, false, false])]
+    #[case::always(Repeat::Always, vec![true, false, true])]
+    fn characters_greet_the_player(#[case] repeat: Repeat, #[case] expected: Vec<bool>) {
+        let room = |name: &str, characters: Vec<CharacterId>| RoomVariantEntity {
+            name: name.into(),
+            description: "".into(),
+            first_description: None,
+            short_description: None,
+            art: None,
+            characters,
+            exits: IndexMap::new(),
+            exit_aliases: IndexMap::new(),
+            travel_times: IndexMap::new(),
+            actions: vec![],
+            fragments: vec![],
+            requires: vec![],
+            priority: 0,
+        };
+        let dialogues = vec![vec![DialogueVariantEntity {
+            text: "Halt! Who goes there?".into(),
+            requires: vec![],
+            responses: vec![],
+            fragments: vec![],
+            priority: 0,
+            speaker: None,
+        }]];
+        let characters = vec![CharacterEntity {
+            name: "Guard".into(),
+            start_dialogue: 0.into(),
+            aliases: vec![],
+            greet_dialogue: Some(0.into()),
+            greet_requires: vec![Requirement::HasItem(0.into())],
+            greet_repeat: repeat,
+            pronouns: Pronouns::default(),
+            art: None,
+        }];
+        let mut game = make_game()
+            .rooms(vec![
+                vec![room("Road", vec![])],
+                vec![room("Gate", vec![0.into()])],
+            ])
+            .dialogues(dialogues)
+            .characters(characters)
+            .call();
+        assert!(!game.has_greeting());
+
+        game.enter_room(1.into());
+        assert_eq!(game.has_greeting(), expected[0]);
+        assert_eq!(
+            game.take_greeting(),
+            Some((CharacterId::from(0), DialogueId::from(0)))
+        );
+        assert_eq!(game.has_greeting(), expected[1]);
+
+        game.enter_room(0.into());
+        game.enter_room(1.into());
+        assert_eq!(game.has_greeting(), expected[2]);
+
+        game.inventory.clear();
+        assert!(!game.has_greeting());
+    }
+    #[rstest]
+    fn dialogue_names_its_speaker() {
+        let dialogue = |text: &str, speaker| {
+            vec![DialogueVariantEntity {
+                text: text.into(),
+                requires: vec![],
+                responses: vec![],
+                fragments: vec![],
+                priority: 0,
+                speaker,
+            }]
+        };
+        let character = |name: &str| CharacterEntity {
+            name: name.into(),
+            start_dialogue: 0.into(),
+            aliases: vec![],
+            greet_dialogue: None,
+            greet_requires: vec![],
+            greet_repeat: Repeat::Times(1),
+            pronouns: Pronouns::default(),
+            art: None,
+        };
+        let game = make_game()
+            .dialogues(vec![
+                dialogue("Pass the rolls, please.", None),
+                dialogue("I want the rolls!", Some(1.into())),
+            ])
+            .characters(vec![character("Old Man"), character("Pesky Kid")])
+            .call();
+        let own_line = DialogueId::from(0).into_proxy(&game);
+        assert!(own_line.speaker().is_none());
+        let hand_off = DialogueId::from(1).into_proxy(&game);
+        assert_eq!(hand_off.speaker().unwrap().name(), "Pesky Kid");
+    }
+    #[rstest]
+    fn affinity_builds_from_actions_and_responses() {
+        let compliment = ActionEntity::GiveItem(GiveItem {
+            name: "compliment".into(),
+            description: "".into(),
+            common: ActionCommon {
+                affinity: vec![(0.into(), 2), (1.into(), -1)],
+                ..ActionCommon::default()
+            },
+            items: vec![],
+            required: None,
+        });
+        let responses = vec![ResponseEntity {
+            text: "Thanks for dinner!".into(),
+            requires: vec![],
+            leads_to: None,
+            triggers: None,
+            repeat: Repeat::Always,
+            score: Score::default(),
+            affinity: vec![(0.into(), 3)],
+            answer: None,
+            fail_leads_to: None,
+            fail_triggers: None,
+        }];
+        let mut game = make_game()
+            .actions(vec![compliment])
+            .responses(responses)
+            .call();
+        let friendly = Requirement::Affinity(0.into(), ">=5".parse().unwrap());
+        let annoyed = Requirement::Affinity(1.into(), "<0".parse().unwrap());
+        assert!(!game.requirement_met(&friendly));
+        assert!(!game.requirement_met(&annoyed));
+
+        game.do_action(0.into());
+        assert!(!game.requirement_met(&friendly));
+        assert!(game.requirement_met(&annoyed));
+
+        game.choose_response(0.into());
+        assert!(game.requirement_met(&friendly));
+    }
+    #[rstest]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn player_profile_feeds_templates_and_requirements(mut game: GameState) {
+        let named_ada = Requirement::PlayerName("ada".into());
+        let she_her = Requirement::PlayerPronouns("she/her".parse().unwrap());
+        assert!(!game.requirement_met(&named_ada));
+        assert!(!game.requirement_met(&she_her));
+        assert_eq!(game.render("{player:they} waved"), "they waved");
+
+        game.set_player_name("  ");
+        assert_eq!(game.render("{player_name}"), "Traveler");
+        game.set_player_name(" Ada ");
+        game.set_player_pronouns("she/her".parse().unwrap());
+        assert!(game.requirement_met(&named_ada));
+        assert!(game.requirement_met(&she_her));
+        assert_eq!(
+            game.render("{player_name} checked {player:their} bag"),
+            "Ada checked her bag"
+        );
+    }
+    #[rstest]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn player_name_is_not_read_as_markup(mut game: GameState) {
+        game.set_player_name("<b>Ada</i>");
+        let text = game.render("<i>Hello {player_name}</i>");
+        assert_eq!(text, "<i>Hello <<b>Ada<</i></i>");
+        assert_eq!(strip_markup(&text), "Hello <b>Ada</i>");
+    }
+    #[rstest]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn player_pronouns_are_not_read_as_markup(mut game: GameState) {
+        game.set_player_name("<u>Ada");
+        game.set_player_pronouns("<b>xe/xem/xyr/xyrs/xemself".parse().unwrap());
+        let text = game.render("{player_name} lost {player:their} map, {player:they} said");
+        game.record("Note", &text);
+        let entry = game.message_log().entries().last().unwrap();
+        assert_eq!(strip_markup(&entry.text), "<u>Ada lost xyr map, <b>xe said");
+    }
+    #[rstest]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn change_var_updates_variables() {
+        let change_var = |name: &str, changes: &str| {
+            ActionEntity::ChangeVar(ChangeVar {
+                name: name.into(),
+                description: "".into(),
+                common: ActionCommon::default(),
+                required: None,
+                changes: changes
+                    .split(',')
+                    .map(|change| change.parse().unwrap())
+                    .collect(),
+            })
+        };
+        let mut game = make_game()
+            .actions(vec![
+                change_var("loot", "gold+10,keys+1"),
+                change_var("spend", "gold-3"),
+                change_var("rob", "gold=0"),
+            ])
+            .call();
+        assert_eq!(game.render("{var:gold} gold"), "0 gold");
+
+        game.do_action(0.into());
+        game.do_action(1.into());
+        assert_eq!(
+            game.render("{var:gold} gold, {var:keys} key"),
+            "7 gold, 1 key"
+        );
+
+        game.do_action(2.into());
+        assert_eq!(game.render("{var:gold} gold"), "0 gold");
+    }
+
+    #[rstest]
+    #[case::player_name("Welcome, {player_name}!", "Welcome, Traveler!")]
+    #[case::score("Score: {var:score}", "Score: 0")]
+    #[case::room_and_items("{var:room}: {var:items} items", "WoodShed: 1 items")]
+    #[case::item("You hold a {item:key}.", "You hold a key.")]
+    #[case::item_count(
+        "Keys: {item:key.count}, rings: {item:ring.count}",
+        "Keys: 1, rings: 0"
+    )]
+    #[case::character(
+        "{char:OldMan} likes you {char:OldMan.affinity}",
+        "Old Man likes you 0"
+    )]
+    #[case::escaped("{{player_name}}", "{player_name}")]
+    #[case::pronouns(
+        "{OldMan.They} {OldMan.are} looking for {OldMan.their} cane",
+        "He is looking for his cane"
+    )]
+    #[case::plural_pronouns("{Twins.They} {Twins.are} waiting", "They are waiting")]
+    #[case::verb_suffix(
+        "{OldMan.they} nap{OldMan.s}, {Twins.they} nap{Twins.s}",
+        "he naps, they nap"
+    )]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn render_fills_placeholders(#[case] text: &str, #[case] expected: &str) {
+        let character = |name: &str, pronouns: &str| CharacterEntity {
+            name: name.into(),
+            start_dialogue: 0.into(),
+            aliases: vec![],
+            greet_dialogue: None,
+            greet_requires: vec![],
+            greet_repeat: Repeat::Times(1),
+            pronouns: pronouns.parse().unwrap(),
+            art: None,
+        };
+        let characters = vec![
+            character("Old Man", "he/him"),
+            character("Twins", "they/them"),
+        ];
+        let game = make_game().characters(characters).call();
+        assert_eq!(game.render(text), expected);
+    }
+
+    #[rstest]
+    fn record_changes_logs_rooms_and_inventory(mut game: GameState) {
+        game.record_changes();
+        game.inventory.insert(0.into());
+        game.record_changes();
+        game.inventory.remove(&0.into());
+        game.inventory.insert(1.into());
+        game.record_changes();
+        game.record_changes();
+        let entries = game
+            .message_log()
+            .entries()
+            .map(|e| (e.heading.as_str(), e.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            [
+                ("WoodShed", "A Shed"),
+                ("Inventory", "Gained: key"),
+                ("Inventory", "Lost: key"),
+                ("Inventory", "Gained: ring"),
+            ]
+        );
+    }
 }
@@ -3,9 +3,10 @@ use std::rc::Rc;
 use bon::Builder;
 
 use super::{
-    ActionEntity, ActionId, CharacterEntity, CharacterId, DialogueEntity, DialogueId, GameTitle,
-    Item, ItemId, Language, ResponseEntity, ResponseId, RoomEntity, RoomId, RoomVariantEntity,
-    RoomVariantId, Theme,
+    AchievementEntity, AchievementId, ActionEntity, ActionId, CharacterEntity, CharacterId,
+    DialogueEntity, DialogueId, FragmentEntity, FragmentId, GameTitle, Identifier, Item, ItemId,
+    Language, QuestEntity, QuestId, QuestStageEntity, QuestStageId, ResponseEntity, ResponseId,
+    RoomEntity, RoomId, RoomVariantEntity, RoomVariantId, Theme, Title,
 };
 
 #[derive(Debug, Builder)]
@@ -21,6 +22,9 @@ pub struct World {
     dialogues: Vec<DialogueEntity>,
     characters: Vec<CharacterEntity>,
     responses: Vec<ResponseEntity>,
+    fragments: Vec<FragmentEntity>,
+    quests: Vec<QuestEntity>,
+    achievements: Vec<AchievementEntity>,
 }
 
 use world_builder::{IsUnset, SetLanguage, SetTheme, State};
@@ -53,9 +57,25 @@ impl World {
     pub fn item(&self, id: ItemId) -> &Item {
         &self.items[usize::from(id)]
     }
+    pub fn find_item(&self, name: &Identifier) -> Option<(ItemId, &Item)> {
+        self.items
+            .iter()
+            .enumerate()
+            .find(|(_, item)| &item.name == name)
+            .map(|(id, item)| (id.into(), item))
+    }
     pub fn action(&self, id: ActionId) -> &ActionEntity {
         &self.actions[usize::from(id)]
     }
+    pub fn actions(&self) -> impl Iterator<Item = (ActionId, &ActionEntity)> {
+        self.actions
+            .iter()
+            .enumerate()
+            .map(|(id, a)| (id.into(), a))
+    }
+    pub fn room_variants(&self, id: RoomId) -> &RoomEntity {
+        &self.rooms[usize::from(id)]
+    }
     pub fn room(&self, id: RoomId, variant_id: Option<RoomVariantId>) -> &RoomVariantEntity {
         &self.rooms[usize::from(id)][variant_id.map_or(0usize, |id| id.into())]
     }
@@ -65,7 +85,27 @@ impl World {
     pub fn character(&self, id: CharacterId) -> &CharacterEntity {
         &self.characters[usize::from(id)]
     }
+    pub fn find_character(&self, name: &Title) -> Option<(CharacterId, &CharacterEntity)> {
+        let name = name.to_string();
+        self.characters
+            .iter()
+            .enumerate()
+            .find(|(_, character)| character.name == name)
+            .map(|(id, character)| (id.into(), character))
+    }
     pub fn response(&self, id: ResponseId) -> &ResponseEntity {
         &self.responses[usize::from(id)]
     }
+    pub fn fragment(&self, id: FragmentId) -> &FragmentEntity {
+        &self.fragments[usize::from(id)]
+    }
+    pub fn quest(&self, id: QuestId, stage_id: Option<QuestStageId>) -> &QuestStageEntity {
+        &self.quests[usize::from(id)][stage_id.map_or(0usize, |id| id.into())]
+    }
+    pub fn achievements(&self) -> impl Iterator<Item = (AchievementId, &AchievementEntity)> {
+        self.achievements
+            .iter()
+            .enumerate()
+            .map(|(id, a)| (id.into(), a))
+    }
 }
@@ -41,6 +41,38 @@ pub enum Application {
         "Found potential circular reference in `{etype}` with id `{parent_id}` from link to child `{child_id}`"
     )]
     CircularReferenceFound { etype: S, parent_id: S, child_id: S },
+    #[error(
+        "Variants `{first}` and `{second}` of `{etype}` with id `{id}` can both match with the same priority! Give one of them a higher `priority`."
+    )]
+    AmbiguousVariants {
+        etype: S,
+        id: S,
+        first: S,
+        second: S,
+    },
+    #[error("Alias `{alias}` in room `{room}` is used by both {first} and {second}!")]
+    DuplicateAlias {
+        room: S,
+        alias: S,
+        first: S,
+        second: S,
+    },
+    #[error(
+        "Unknown placeholder `{{{placeholder}}}` in field `{field}` of `{etype}` with id `{id}`!"
+    )]
+    UnknownPlaceholder {
+        placeholder: S,
+        etype: S,
+        id: S,
+        field: S,
+    },
+    #[error("Malformed markup in field `{field}` of `{etype}` with id `{id}`: {problem}!")]
+    MalformedMarkup {
+        problem: S,
+        etype: S,
+        id: S,
+        field: S,
+    },
 }
 
 pub use Application::*;
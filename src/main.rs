@@ -1,16 +1,22 @@
 #![deny(clippy::unwrap_used, clippy::expect_used)]
 #![warn(clippy::all, clippy::nursery)]
 
+mod command;
 mod config_parser;
 mod core;
 mod error;
 mod player;
+mod typewriter;
 mod ui;
 
 use clap::Parser;
+use command::{Command, Vocabulary};
 use config_parser::preprocess_to_ini_from_file;
-use core::{Action, ActionId, CharacterId, DialogueId, GameState, IntoProxy, ResponseId, Room};
-use player::Player;
+use core::{
+    Action, ActionId, CharacterId, Dialogue, DialogueId, GameState, IntoProxy, ItemId, Lookup,
+    QuestStatus, Response, ResponseId, Room, RoomId, escape_markup,
+};
+use player::{Conversation, Player};
 use std::{fs::File, path::PathBuf};
 use tracing::{self, info};
 use tracing_subscriber::{EnvFilter, fmt::writer::BoxMakeWriter};
@@ -21,6 +27,9 @@ use ui::*;
 struct Args {
     #[arg(short, long)]
     file: PathBuf,
+    /// Type verb-noun commands instead of choosing from menus
+    #[arg(short, long)]
+    parser: bool,
 }
 
 fn main() {
@@ -44,32 +53,119 @@ fn play() -> Result<(), error::Application> {
         .map_err(|e| error::CouldNotLoadFile(e.to_string().into()))?;
     let mut state = GameState::from_ini(ini)?;
     let mut ui = UI::new(state.theme(), state.language());
-    let mut player = Player::Idle;
+    let mut player = if state.asks_player_name() {
+        P::EnteringName
+    } else if state.asks_pronouns() {
+        P::EnteringPronouns(false)
+    } else {
+        P::Idle
+    };
     info!("Staring game...");
-    ui.greet(state.title(), state.greeting());
+    let (title, greeting) = (state.title().clone(), state.greeting());
+    state.record(&title, &greeting);
+    ui.greet(&title, &greeting, state.title_art());
     while player != P::GameOver {
-        info!("State {:#?}", player.clone());
+        info!("Turn {} state {:#?}", state.turns(), player.clone());
+        ui.set_language(state.language());
+        state.record_changes();
+        ui.set_log(state.message_log());
+        ui.set_status(state.status_bar().as_deref());
         player = match player {
+            P::EnteringName => entering_name(&mut state, &mut ui),
+            P::EnteringPronouns(retry) => entering_pronouns(&mut state, &mut ui, retry),
+            P::Idle if state.has_events() => happening(&mut state, &mut ui),
+            P::Idle if state.has_greeting() => greeted(&mut state),
+            P::Idle if args.parser => typing_command(&state, &mut ui, None),
             P::Idle => idle(&state, &mut ui),
+            P::Hinting(hint) => typing_command(&state, &mut ui, Some(&hint)),
+            P::Examining(item) => examining(&mut state, &mut ui, item),
+            P::GoingTo(room) => going_to(&mut state, room),
             P::ViewInventory => view_inventory(&state, &mut ui),
+            P::ViewJournal => view_journal(&state, &mut ui),
+            P::TogglingVerbose => toggling_verbose(&mut state),
             P::StartingChat => starting_chat(&state, &mut ui),
-            P::ChatWith(character, dialogue) => chat_with(&state, &mut ui, character, dialogue),
-            P::DoActionInChatResponse(action, character, response) => {
-                do_action_in_chat_response(&mut state, &mut ui, action, character, response)
+            P::ChatWith(character, dialogue, conversation) => {
+                chat_with(&mut state, &mut ui, character, dialogue, conversation)
+            }
+            P::ViewChatHistory(character, dialogue, conversation) => {
+                view_chat_history(&state, &mut ui, character, dialogue, conversation)
+            }
+            P::AnsweringResponse(character, response, conversation) => {
+                answering_response(&mut state, &mut ui, character, response, conversation)
+            }
+            P::DoActionInChatResponse(action, character, response, leads_to, conversation) => {
+                do_action_in_chat_response(
+                    &mut state,
+                    &mut ui,
+                    action,
+                    character,
+                    response,
+                    leads_to,
+                    conversation,
+                )
             }
             P::SelectingAction => selecting_action(&state, &mut ui),
             P::DoingAction(action) => doing_action(&mut state, &mut ui, action),
-            P::Leaving => leaving(&mut state, &mut ui),
+            P::Leaving => leaving(&state, &mut ui),
             P::GameOver => panic!("GameOver state should be unreachable in update loop!"),
         }
     }
     if state.current_room().is_trap() {
         info!("Rolling credits...");
-        ui.roll_credits(state.title(), state.credits());
+        let achievements = state
+            .achievements()
+            .iter()
+            .map(|a| format!("{}: {}", a.name, state.render(&a.description)))
+            .collect::<Vec<_>>();
+        ui.roll_credits(
+            state.title(),
+            &state.credits(),
+            state
+                .has_score()
+                .then(|| (state.score(), state.max_score())),
+            &achievements,
+        );
     }
     info!("Finished.");
     Ok(())
 }
+fn record(state: &mut GameState, ui: &mut UI, heading: &str, text: &str) {
+    state.record(heading, text);
+    ui.set_log(state.message_log());
+}
+fn entering_name(state: &mut GameState, ui: &mut UI) -> Player {
+    use Player as P;
+    let name = ui.present_answer(state.title(), state.language().player_name_prompt());
+    state.set_player_name(&name);
+    if state.asks_pronouns() {
+        P::EnteringPronouns(false)
+    } else {
+        P::Idle
+    }
+}
+fn entering_pronouns(state: &mut GameState, ui: &mut UI, retry: bool) -> Player {
+    use Player as P;
+    let language = state.language();
+    let question = if retry {
+        format!(
+            "{}\n{}",
+            language.unknown_pronouns(),
+            language.pronouns_prompt()
+        )
+    } else {
+        language.pronouns_prompt().to_string()
+    };
+    let answer = ui.present_answer(state.title(), &question);
+    if answer.trim().is_empty() {
+        return P::Idle;
+    }
+    answer
+        .parse()
+        .map_or(P::EnteringPronouns(true), |pronouns| {
+            state.set_player_pronouns(pronouns);
+            P::Idle
+        })
+}
 fn idle(state: &GameState, ui: &mut UI) -> Player {
     use Player as P;
     let room = state.current_room();
@@ -82,13 +178,18 @@ fn idle(state: &GameState, ui: &mut UI) -> Player {
         .map(|e| e.direction().to_string())
         .collect::<Vec<_>>();
     let actions = room.actions().next().is_some();
+    let clock = state.clock().map(|c| c.to_string());
     let choice = ui.present_room(
         room.name(),
-        room.description(),
+        &room.description(),
         &characters,
         &exits,
         actions,
         state.has_inventory(),
+        state.has_journal(),
+        room.has_short_description().then(|| state.verbose()),
+        clock.as_deref(),
+        room.art(),
     );
     use RoomChoice as C;
     match choice {
@@ -97,57 +198,291 @@ fn idle(state: &GameState, ui: &mut UI) -> Player {
         C::Leave => P::Leaving,
         C::GameOver => P::GameOver,
         C::ViewInventory => P::ViewInventory,
+        C::ViewJournal => P::ViewJournal,
+        C::ToggleVerbose => P::TogglingVerbose,
+    }
+}
+fn typing_command(state: &GameState, ui: &mut UI, hint: Option<&str>) -> Player {
+    use Player as P;
+    let room = state.current_room();
+    if room.is_trap() {
+        return idle(state, ui);
+    }
+    let mut vocabulary = Vocabulary::default();
+    for exit in room.exits() {
+        vocabulary = vocabulary.exit(
+            &exit.direction().to_string(),
+            exit.aliases(),
+            exit.room().id(),
+        );
+    }
+    for character in room.characters() {
+        vocabulary = vocabulary.character(character.name(), character.aliases(), character.id());
+    }
+    for action in room.actions() {
+        vocabulary = vocabulary.action(&action.name(), action.aliases(), action.id());
+    }
+    for (item, name, aliases) in state.carried_items() {
+        vocabulary = vocabulary.item(&name, &aliases, item);
+    }
+    let characters = room
+        .characters()
+        .map(|v| v.name().to_string())
+        .collect::<Vec<_>>();
+    let exits = room
+        .exits()
+        .map(|e| e.direction().to_string())
+        .collect::<Vec<_>>();
+    let clock = state.clock().map(|c| c.to_string());
+    let input = ui.present_command(
+        room.name(),
+        &room.description(),
+        &characters,
+        &exits,
+        clock.as_deref(),
+        hint,
+        room.art(),
+    );
+    let language = state.language();
+    let hints = || {
+        format!(
+            "{} {}",
+            language.known_commands(),
+            vocabulary.hints().join(", ")
+        )
+    };
+    match vocabulary.parse(&input) {
+        Command::Go(room) => P::GoingTo(room),
+        Command::Talk(character) => P::ChatWith(character, None, Conversation::default()),
+        Command::Do(action) => P::DoingAction(action),
+        Command::Examine(item) => P::Examining(item),
+        Command::Look => P::Idle,
+        Command::Inventory => P::ViewInventory,
+        Command::Journal => P::ViewJournal,
+        Command::Help => P::Hinting(hints()),
+        Command::Unknown => P::Hinting(format!("{} {}", language.unknown_command(), hints())),
+    }
+}
+fn examining(state: &mut GameState, ui: &mut UI, item: ItemId) -> Player {
+    use Player as P;
+    let name = state
+        .carried_items()
+        .into_iter()
+        .find_map(|(id, name, _)| (id == item).then_some(name))
+        .unwrap_or_default();
+    let description = state.item_description(item);
+    record(state, ui, &name, &description);
+    ui.present_action(&name, &description, true);
+    P::Idle
+}
+fn happening(state: &mut GameState, ui: &mut UI) -> Player {
+    use Player as P;
+    for event in state.take_events() {
+        let action = event.into_proxy(state);
+        let (name, description) = (action.name(), action.description());
+        record(state, ui, &name, &description);
+        ui.present_action(&name, &description, true);
     }
+    P::Idle
+}
+fn greeted(state: &mut GameState) -> Player {
+    use Player as P;
+    state
+        .take_greeting()
+        .map_or(P::Idle, |(character, dialogue)| {
+            P::ChatWith(character, Some(dialogue), Conversation::default())
+        })
+}
+fn going_to(state: &mut GameState, room: RoomId) -> Player {
+    use Player as P;
+    Room::<GameState>::enter(room, state);
+    P::Idle
 }
 fn view_inventory(state: &GameState, ui: &mut UI) -> Player {
     use Player as P;
     ui.present_inventory(&state.inventory());
     P::Idle
 }
+fn view_journal(state: &GameState, ui: &mut UI) -> Player {
+    use Player as P;
+    let mut active = Vec::new();
+    let mut completed = Vec::new();
+    let mut failed = Vec::new();
+    for quest in state.journal() {
+        let entry = format!("{}: {}", quest.name(), quest.description());
+        match quest.status() {
+            QuestStatus::Active => active.push(entry),
+            QuestStatus::Completed => completed.push(entry),
+            QuestStatus::Failed => failed.push(entry),
+            QuestStatus::NotStarted => {}
+        }
+    }
+    ui.present_journal(&active, &completed, &failed);
+    P::Idle
+}
+const fn toggling_verbose(state: &mut GameState) -> Player {
+    use Player as P;
+    state.toggle_verbose();
+    P::Idle
+}
 fn starting_chat(state: &GameState, ui: &mut UI) -> Player {
     use Player as P;
     let room = state.current_room();
     let characters = room.characters();
     let characters_names = characters.map(|v| v.name().to_string()).collect::<Vec<_>>();
-    let choice = ui.present_chat_targets(room.name(), room.description(), &characters_names);
+    let choice = ui.present_chat_targets(room.name(), &room.description(), &characters_names);
     let characters = room.characters().collect::<Vec<_>>();
     use StartChatChoice as C;
     match choice {
-        C::TalkTo(i) => P::ChatWith(characters[i].id(), None),
+        C::TalkTo(i) => P::ChatWith(characters[i].id(), None, Conversation::default()),
         C::NoOne => P::Idle,
     }
 }
 fn chat_with(
-    state: &GameState,
+    state: &mut GameState,
     ui: &mut UI,
     character: CharacterId,
     dialogue: Option<DialogueId>,
+    conversation: Conversation,
 ) -> Player {
     use Player as P;
+    let (character, dialogue) = {
+        let character = character.into_proxy(state);
+        let dialogue = dialogue
+            .map(|d| d.into_proxy(state))
+            .unwrap_or_else(|| character.start_dialogue());
+        let speaker = dialogue
+            .speaker()
+            .map_or_else(|| character.id(), |s| s.id());
+        (speaker, dialogue.id())
+    };
+    let text = dialogue.into_proxy(state).text();
+    let speaker = character.into_proxy(state).name().to_string();
+    record(state, ui, &speaker, &text);
     let character = character.into_proxy(state);
-    let dialogue = dialogue
-        .map(|d| d.into_proxy(state))
-        .unwrap_or_else(|| character.start_dialogue());
+    let dialogue = dialogue.into_proxy(state);
     let responses = dialogue.responses().collect::<Vec<_>>();
-    let response_text = responses
-        .iter()
-        .map(|v| v.text().to_string())
-        .collect::<Vec<_>>();
-    let choice = ui.present_chat(character.name(), dialogue.text(), &response_text);
+    let response_text = responses.iter().map(|v| v.text()).collect::<Vec<_>>();
+    let chosen = responses.iter().map(|v| v.was_chosen()).collect::<Vec<_>>();
+    let exhausted_text = if state.show_exhausted() {
+        dialogue
+            .exhausted_responses()
+            .map(|v| v.text())
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    let choice = ui.present_chat(
+        character.name(),
+        &text,
+        &response_text,
+        &chosen,
+        &exhausted_text,
+        conversation.can_go_back(),
+        !conversation.history().is_empty(),
+        character.art(),
+    );
+    let dialogue_id = dialogue.id();
     use ChatChoice as C;
-    match choice {
+    let next = match choice {
         C::RespondWith(i) => {
             let response = &responses[i];
-            #[allow(clippy::option_if_let_else)]
-            if let Some(action) = response.trigger() {
-                P::DoActionInChatResponse(action.into_id(), character.id(), response.id())
+            let response_id = response.id();
+            let conversation = conversation
+                .said(format!("{}: {text}", character.name()))
+                .visited(character.id(), dialogue_id);
+            if response.asks_answer() {
+                P::AnsweringResponse(character.id(), response_id, conversation)
             } else {
-                response
-                    .leads_to()
-                    .map_or(P::Idle, |d| P::ChatWith(character.id(), Some(d.id())))
+                let reply = format!("> {}", response.text());
+                let trigger = response.trigger().map(|a| a.id());
+                let next = following_response(
+                    character.id(),
+                    Some(response_id),
+                    trigger,
+                    response.leads_to().map(|d| d.id()),
+                    conversation.said(reply.clone()),
+                );
+                if trigger.is_none() {
+                    Response::<GameState>::choose(response_id, state);
+                }
+                record(state, ui, "", &reply);
+                next
             }
         }
+        C::Back => conversation
+            .back()
+            .map_or(P::Idle, |(character, dialogue, conversation)| {
+                P::ChatWith(character, Some(dialogue), conversation)
+            }),
+        C::ViewHistory => P::ViewChatHistory(character.id(), dialogue_id, conversation),
         C::Leave => P::Idle,
+    };
+    Dialogue::<GameState>::view(dialogue_id, state);
+    next
+}
+fn view_chat_history(
+    state: &GameState,
+    ui: &mut UI,
+    character: CharacterId,
+    dialogue: DialogueId,
+    conversation: Conversation,
+) -> Player {
+    use Player as P;
+    ui.present_chat_history(character.into_proxy(state).name(), conversation.history());
+    P::ChatWith(character, Some(dialogue), conversation)
+}
+fn answering_response(
+    state: &mut GameState,
+    ui: &mut UI,
+    character: CharacterId,
+    response: ResponseId,
+    conversation: Conversation,
+) -> Player {
+    let character_name = character.into_proxy(state).name().to_string();
+    let proxy = response.into_proxy(state);
+    let answer = ui.present_answer(&character_name, &proxy.text());
+    // Typed text is shown as typed, not read as markup
+    let reply = format!("> {}", escape_markup(&answer));
+    let conversation = conversation.said(reply.clone());
+    let next = if proxy.accepts(&answer) {
+        let trigger = proxy.trigger().map(|a| a.id());
+        let next = following_response(
+            character,
+            Some(response),
+            trigger,
+            proxy.leads_to().map(|d| d.id()),
+            conversation,
+        );
+        if trigger.is_none() {
+            Response::<GameState>::choose(response, state);
+        }
+        next
+    } else {
+        let next = following_response(
+            character,
+            None,
+            proxy.fail_trigger().map(|a| a.id()),
+            proxy.fail_leads_to().map(|d| d.id()),
+            conversation,
+        );
+        Response::<GameState>::fail(response, state);
+        next
+    };
+    record(state, ui, "", &reply);
+    next
+}
+fn following_response(
+    character: CharacterId,
+    response: Option<ResponseId>,
+    action: Option<ActionId>,
+    leads_to: Option<DialogueId>,
+    conversation: Conversation,
+) -> Player {
+    use Player as P;
+    match action {
+        Some(a) => P::DoActionInChatResponse(a, character, response, leads_to, conversation),
+        None => leads_to.map_or(P::Idle, |d| P::ChatWith(character, Some(d), conversation)),
     }
 }
 fn do_action_in_chat_response(
@@ -155,25 +490,41 @@ fn do_action_in_chat_response(
     ui: &mut UI,
     action: ActionId,
     character: CharacterId,
-    response: ResponseId,
+    response: Option<ResponseId>,
+    leads_to: Option<DialogueId>,
+    conversation: Conversation,
 ) -> Player {
     use Player as P;
     let action = action.into_proxy(state);
     let action_name = action.name();
     let action_description = action.description();
-    Action::<GameState>::do_it(action.into_id(), state);
+    let done = Action::<GameState>::do_it(action.into_id(), state);
+    if done && let Some(response) = response {
+        Response::<GameState>::choose(response, state);
+    }
+    ui.set_status(state.status_bar().as_deref());
+    record(state, ui, &action_name, &action_description);
     ui.present_action(action_name.as_str(), action_description.as_str(), true);
-    response
-        .into_proxy(state)
-        .leads_to()
-        .map_or(P::Idle, |d| P::ChatWith(character, Some(d.into_id())))
+    leads_to.map_or(P::Idle, |d| P::ChatWith(character, Some(d), conversation))
 }
 fn selecting_action(state: &GameState, ui: &mut UI) -> Player {
     use Player as P;
     let room = state.current_room();
     let actions = room.actions().collect::<Vec<_>>();
     let action_names = actions.iter().map(|v| v.name()).collect::<Vec<_>>();
-    let choice = ui.present_action_select(room.name(), room.description(), &action_names);
+    let exhausted_names = if state.show_exhausted() {
+        room.exhausted_actions()
+            .map(|v| v.name())
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    let choice = ui.present_action_select(
+        room.name(),
+        &room.description(),
+        &action_names,
+        &exhausted_names,
+    );
     use InteractionChoice as C;
     match choice {
         C::Do(i) => {
@@ -186,15 +537,16 @@ fn selecting_action(state: &GameState, ui: &mut UI) -> Player {
 fn doing_action(state: &mut GameState, ui: &mut UI, action: ActionId) -> Player {
     use Player as P;
     let success = Action::<GameState>::do_it(action, state);
+    ui.set_status(state.status_bar().as_deref());
     let action = action.into_proxy(state);
-    ui.present_action(
-        action.name().as_str(),
-        action.description().as_str(),
-        success,
-    );
+    let (name, description) = (action.name(), action.description());
+    if success {
+        record(state, ui, &name, &description);
+    }
+    ui.present_action(&name, &description, success);
     P::Idle
 }
-fn leaving(state: &mut GameState, ui: &mut UI) -> Player {
+fn leaving(state: &GameState, ui: &mut UI) -> Player {
     use Player as P;
     let room = state.current_room();
     let exits = room.exits().collect::<Vec<_>>();
@@ -202,15 +554,10 @@ fn leaving(state: &mut GameState, ui: &mut UI) -> Player {
         .iter()
         .map(|e| e.direction().to_string())
         .collect::<Vec<_>>();
-    let choice = ui.present_exit_select(room.name(), room.description(), &directions);
+    let choice = ui.present_exit_select(room.name(), &room.description(), &directions);
     use LeaveChoice as C;
     match choice {
-        C::GoTo(i) => {
-            let room = exits[i].room();
-            let room = room.id();
-            Room::<GameState>::enter(room, state);
-            P::Idle
-        }
+        C::GoTo(i) => P::GoingTo(exits[i].room().id()),
         C::Stay => P::Idle,
     }
 }
@@ -1,14 +1,84 @@
-use crate::core::{ActionId, CharacterId, DialogueId, ResponseId};
+use crate::core::{ActionId, CharacterId, DialogueId, ItemId, ResponseId, RoomId};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Player {
-    ChatWith(CharacterId, Option<DialogueId>),
-    DoActionInChatResponse(ActionId, CharacterId, ResponseId),
+    AnsweringResponse(CharacterId, ResponseId, Conversation),
+    ChatWith(CharacterId, Option<DialogueId>, Conversation),
+    DoActionInChatResponse(
+        ActionId,
+        CharacterId,
+        Option<ResponseId>,
+        Option<DialogueId>,
+        Conversation,
+    ),
     DoingAction(ActionId),
+    EnteringName,
+    EnteringPronouns(bool),
+    Examining(ItemId),
     GameOver,
+    GoingTo(RoomId),
+    Hinting(String),
     Idle,
     Leaving,
     SelectingAction,
     StartingChat,
+    TogglingVerbose,
+    ViewChatHistory(CharacterId, DialogueId, Conversation),
     ViewInventory,
+    ViewJournal,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Conversation {
+    trail: Vec<(CharacterId, DialogueId)>,
+    history: Vec<String>,
+}
+
+impl Conversation {
+    pub const fn can_go_back(&self) -> bool {
+        !self.trail.is_empty()
+    }
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+    pub fn said(mut self, line: String) -> Self {
+        self.history.push(line);
+        self
+    }
+    pub fn visited(mut self, character: CharacterId, dialogue: DialogueId) -> Self {
+        self.trail.push((character, dialogue));
+        self
+    }
+    pub fn back(mut self) -> Option<(CharacterId, DialogueId, Self)> {
+        let (character, dialogue) = self.trail.pop()?;
+        Some((character, dialogue, self))
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn conversation_goes_back_along_its_trail() {
+        let conversation = Conversation::default()
+            .said("Old Man: Hello!".into())
+            .visited(0.into(), 1.into())
+            .said("Pesky Kid: Hi!".into())
+            .visited(1.into(), 2.into());
+        assert!(conversation.can_go_back());
+
+        let (character, dialogue, conversation) = conversation.back().unwrap();
+        assert_eq!((character, dialogue), (1.into(), 2.into()));
+        let (character, dialogue, conversation) = conversation.back().unwrap();
+        assert_eq!((character, dialogue), (0.into(), 1.into()));
+        assert!(!conversation.can_go_back());
+        assert_eq!(
+            conversation.history(),
+            ["Old Man: Hello!", "Pesky Kid: Hi!"]
+        );
+        assert!(conversation.back().is_none());
+    }
 }
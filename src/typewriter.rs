@@ -0,0 +1,106 @@
+use std::time::Instant;
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Typewriter<C = SystemClock> {
+    clock: C,
+    started: Instant,
+    length: usize,
+    chars_per_second: u32,
+    skipped: bool,
+}
+
+impl<C: Clock> Typewriter<C> {
+    pub fn new(clock: C, length: usize, chars_per_second: u32) -> Self {
+        Self {
+            started: clock.now(),
+            clock,
+            length,
+            chars_per_second,
+            skipped: false,
+        }
+    }
+    pub fn revealed(&self) -> usize {
+        if self.skipped || self.chars_per_second == 0 {
+            return self.length;
+        }
+        let elapsed = self.clock.now().saturating_duration_since(self.started);
+        let shown = elapsed.as_millis() * u128::from(self.chars_per_second) / 1000;
+        usize::try_from(shown).map_or(self.length, |shown| shown.min(self.length))
+    }
+    pub fn is_done(&self) -> bool {
+        self.revealed() == self.length
+    }
+    pub const fn skip(&mut self) {
+        self.skipped = true;
+    }
+}
+
+// Allowed in tests
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+    use std::{cell::Cell, rc::Rc, time::Duration};
+
+    #[derive(Clone)]
+    struct FakeClock(Rc<Cell<Instant>>);
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self(Rc::new(Cell::new(Instant::now())))
+        }
+        fn advance(&self, millis: u64) {
+            self.0.set(self.0.get() + Duration::from_millis(millis));
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.0.get()
+        }
+    }
+
+    #[rstest]
+    #[case::start(0, 0)]
+    #[case::partway(250, 5)]
+    #[case::rounds_down(299, 5)]
+    #[case::finished(500, 10)]
+    #[case::never_past_the_end(5000, 10)]
+    fn reveals_over_time(#[case] millis: u64, #[case] expected: usize) {
+        let clock = FakeClock::new();
+        let typewriter = Typewriter::new(clock.clone(), 10, 20);
+        clock.advance(millis);
+        assert_eq!(typewriter.revealed(), expected);
+        assert_eq!(typewriter.is_done(), expected == 10);
+    }
+
+    #[test]
+    fn skipping_reveals_everything() {
+        let clock = FakeClock::new();
+        let mut typewriter = Typewriter::new(clock.clone(), 10, 20);
+        clock.advance(100);
+        assert_eq!(typewriter.revealed(), 2);
+        typewriter.skip();
+        assert!(typewriter.is_done());
+    }
+
+    #[test]
+    fn zero_speed_is_instant() {
+        let typewriter = Typewriter::new(FakeClock::new(), 10, 0);
+        assert!(typewriter.is_done());
+    }
+}
@@ -1,24 +1,39 @@
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
 
 use convert_case::Casing;
 use cursive::{
     align::HAlign,
-    theme::{BorderStyle, Color, Effect, PaletteColor, Style, Theme as SivTheme},
+    event::{Event, EventTrigger, Key},
+    theme::{BorderStyle, Color, ColorStyle, Effect, PaletteColor, Style, Theme as SivTheme},
     utils::markup::StyledString,
-    view::{IntoBoxedView, Nameable, Resizable},
+    view::{IntoBoxedView, Nameable, Resizable, ScrollStrategy},
     views::{
-        self, Button, DummyView, LayerPosition, LinearLayout, ScrollView, SelectView, TextView,
+        self, Button, DummyView, EditView, HideableView, Layer, LayerPosition, LinearLayout,
+        NamedView, ScrollView, SelectView, TextView,
     },
     Cursive, CursiveExt,
 };
 
-use crate::core::{Language, Theme, ThemeColor};
+use crate::{
+    core::{Language, MessageLog, TextStyle, Theme, ThemeColor, markup_spans, strip_markup},
+    typewriter::{SystemClock, Typewriter},
+};
+
+const REVEAL_FPS: u32 = 30;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RoomChoice {
     Chat,
     Interact,
     ViewInventory,
+    ViewJournal,
+    ToggleVerbose,
     Leave,
     GameOver,
 }
@@ -44,12 +59,16 @@ pub enum LeaveChoice {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChatChoice {
     RespondWith(usize),
+    Back,
+    ViewHistory,
     Leave,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum UIChoice {
     None,
+    Disabled,
+    Command(String),
     InRoom(RoomChoice),
     StartChat(StartChatChoice),
     InChat(ChatChoice),
@@ -62,15 +81,36 @@ struct MenuItem(String, UIChoice);
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 struct MenuScreen {
+    status: String,
     title: String,
+    art: String,
     body: String,
     prompt: String,
     menu: Vec<MenuItem>,
+    dimmed: Vec<usize>,
+    command: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct LogPane {
+    title: StyledString,
+    text: StyledString,
+    close: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Reveal {
+    view: &'static str,
+    text: StyledString,
+    hidden: Style,
+    typewriter: Typewriter,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct UIState {
     choice: UIChoice,
+    log: LogPane,
+    reveal: Option<Reveal>,
 }
 
 pub struct UI {
@@ -78,6 +118,8 @@ pub struct UI {
     theme: Rc<Theme>,
     language: Rc<Language>,
     screen: MenuScreen,
+    status: String,
+    revealing: Arc<AtomicBool>,
 }
 
 impl From<&ThemeColor> for Color {
@@ -99,17 +141,63 @@ impl UI {
         siv_theme.borders = BorderStyle::None;
         siv.set_theme(siv_theme);
         siv.add_global_callback('q', |s| s.quit());
+        siv.add_global_callback('l', Self::toggle_log);
+        siv.add_global_callback(Key::Esc, |s| s.quit());
+        siv.add_global_callback(Event::CtrlChar('l'), Self::toggle_log);
+        let revealing = Arc::new(AtomicBool::new(false));
+        let skipping = revealing.clone();
+        let skip_trigger = revealing.clone();
+        siv.set_on_pre_event(
+            EventTrigger::from_fn(move |event| {
+                skip_trigger.load(Ordering::Relaxed) && is_keypress(event)
+            }),
+            move |s| Self::skip_reveal(s, &skipping),
+        );
+        let stepping = revealing.clone();
+        siv.add_global_callback(Event::Refresh, move |s| Self::step_reveal(s, &stepping));
         siv.set_user_data(UIState {
             choice: UIChoice::None,
+            log: LogPane::default(),
+            reveal: None,
         });
         Self {
             siv,
             theme,
             language,
             screen: MenuScreen::default(),
+            status: String::new(),
+            revealing,
         }
     }
-    pub fn greet(&mut self, title: &str, greeting: &str) {
+    pub fn set_language(&mut self, language: Rc<Language>) {
+        self.language = language;
+    }
+    pub fn set_status(&mut self, status: Option<&str>) {
+        self.status = status.unwrap_or_default().into();
+    }
+    pub fn set_log(&mut self, log: &MessageLog) {
+        let heading = Style::from(Color::from(self.theme.heading())).combine(Effect::Bold);
+        let mut text = StyledString::new();
+        for entry in log.entries() {
+            if !text.is_empty() {
+                text.append_plain("\n\n");
+            }
+            if !entry.heading.is_empty() {
+                text.append_styled(entry.heading.as_str(), heading);
+                text.append_plain("\n");
+            }
+            text.append(self.styled(&entry.text));
+        }
+        let pane = LogPane {
+            title: StyledString::styled(self.language.message_log().as_str(), heading),
+            text,
+            close: self.language.continue_game().clone(),
+        };
+        self.siv.with_user_data(|data: &mut UIState| {
+            data.log = pane;
+        });
+    }
+    pub fn greet(&mut self, title: &str, greeting: &str, art: Option<&str>) {
         let mut title_str = StyledString::new();
         title_str.append_styled(
             title,
@@ -117,16 +205,22 @@ impl UI {
         );
         let title_view = TextView::new(title_str).h_align(HAlign::Center);
 
-        let mut greeting_str = StyledString::new();
-        greeting_str.append_plain(greeting);
-        let greeting_view = TextView::new(greeting_str).h_align(HAlign::Center);
+        let greeting_view = TextView::new(StyledString::new())
+            .h_align(HAlign::Center)
+            .with_name("greeting");
         let greeting_view = ScrollView::new(greeting_view);
 
         let pause = self.pause_for_any_key_view();
 
-        let layout = LinearLayout::vertical()
+        let mut layout = LinearLayout::vertical()
             .child(DummyView.full_height())
-            .weight(1)
+            .weight(1);
+        if let Some(art) = art {
+            layout = layout
+                .child(Self::art_view(art))
+                .child(DummyView.fixed_height(1));
+        }
+        let layout = layout
             .child(title_view)
             .child(DummyView.fixed_height(2))
             .child(greeting_view)
@@ -141,10 +235,17 @@ impl UI {
             .child(DummyView.full_width())
             .weight(1);
         self.swap_layer(layout);
+        self.reveal("greeting", self.styled(greeting));
         self.siv.run();
         self.switch_to_menu_screen();
     }
-    pub fn roll_credits(&mut self, title: &str, credits: &str) {
+    pub fn roll_credits(
+        &mut self,
+        title: &str,
+        credits: &str,
+        score: Option<(i32, Option<i32>)>,
+        achievements: &[String],
+    ) {
         let mut title_str = StyledString::new();
         title_str.append_styled(
             title,
@@ -152,8 +253,19 @@ impl UI {
         );
         let title_view = TextView::new(title_str).h_align(HAlign::Center);
 
-        let mut credits_str = StyledString::new();
-        credits_str.append_plain(credits);
+        let mut credits_str = self.styled(credits);
+        if let Some((score, max_score)) = score {
+            let score =
+                max_score.map_or_else(|| score.to_string(), |max| format!("{score} / {max}"));
+            credits_str.append_plain("\n\n");
+            credits_str.append_styled(self.language.final_score(), Effect::Bold);
+            credits_str.append_plain(format!(" {score}"));
+        }
+        if !achievements.is_empty() {
+            credits_str.append_plain("\n\n");
+            credits_str.append_styled(self.language.achievements_unlocked(), Effect::Bold);
+            credits_str.append_plain(format!("\n{}", achievements.join("\n")));
+        }
         let credits_view = TextView::new(credits_str).h_align(HAlign::Center);
         let credits_view = ScrollView::new(credits_view);
 
@@ -178,6 +290,7 @@ impl UI {
         self.swap_layer(layout);
         self.siv.run();
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn present_room(
         &mut self,
         room_name: &str,
@@ -186,16 +299,14 @@ impl UI {
         exits: &[String],
         has_actions: bool,
         has_inventory: bool,
+        has_journal: bool,
+        verbose: Option<bool>,
+        clock: Option<&str>,
+        art: Option<&str>,
     ) -> RoomChoice {
         let mut menu = Vec::new();
-        let mut body = String::new();
-        body.push_str(room_description);
-        body.push_str("\n\n");
+        let body = self.room_body(room_description, characters, exits, clock);
         if !characters.is_empty() {
-            body.push_str(self.language.characters_found());
-            body.push(' ');
-            body.push_str(&characters.join(", "));
-            body.push('\n');
             menu.push(MenuItem(
                 self.language.talk().into(),
                 UIChoice::InRoom(RoomChoice::Chat),
@@ -208,16 +319,6 @@ impl UI {
             ));
         }
         if !exits.is_empty() {
-            body.push_str(self.language.exits_found());
-            body.push(' ');
-            body.push_str(
-                &exits
-                    .iter()
-                    .map(|s| s.to_string().to_case(convert_case::Case::Title))
-                    .collect::<Vec<_>>()
-                    .join(", "),
-            );
-            body.push('\n');
             menu.push(MenuItem(
                 self.language.go_somewhere().into(),
                 UIChoice::InRoom(RoomChoice::Leave),
@@ -228,6 +329,23 @@ impl UI {
                     UIChoice::InRoom(RoomChoice::ViewInventory),
                 ));
             }
+            if has_journal {
+                menu.push(MenuItem(
+                    self.language.view_journal().into(),
+                    UIChoice::InRoom(RoomChoice::ViewJournal),
+                ));
+            }
+            if let Some(verbose) = verbose {
+                let label = if verbose {
+                    self.language.brief_descriptions()
+                } else {
+                    self.language.verbose_descriptions()
+                };
+                menu.push(MenuItem(
+                    label.into(),
+                    UIChoice::InRoom(RoomChoice::ToggleVerbose),
+                ));
+            }
         } else {
             menu.push(MenuItem(
                 self.language.end_game().into(),
@@ -236,9 +354,11 @@ impl UI {
         }
         self.show_menu(MenuScreen {
             title: room_name.into(),
+            art: art.unwrap_or_default().into(),
             prompt: self.language.choose_action().into(),
             body,
             menu,
+            ..MenuScreen::default()
         });
         if let Some(UIState {
             choice: UIChoice::InRoom(choice),
@@ -250,6 +370,80 @@ impl UI {
             panic!("Expected choice in room prompt!");
         }
     }
+    #[allow(clippy::too_many_arguments)]
+    pub fn present_command(
+        &mut self,
+        room_name: &str,
+        room_description: &str,
+        characters: &[String],
+        exits: &[String],
+        clock: Option<&str>,
+        feedback: Option<&str>,
+        art: Option<&str>,
+    ) -> String {
+        let mut body = self.room_body(room_description, characters, exits, clock);
+        if let Some(feedback) = feedback {
+            body.push('\n');
+            body.push_str(feedback);
+        }
+        self.show_menu(MenuScreen {
+            title: room_name.into(),
+            art: art.unwrap_or_default().into(),
+            prompt: self.language.choose_action().into(),
+            body,
+            menu: vec![],
+            command: true,
+            ..MenuScreen::default()
+        });
+        self.typed_text()
+    }
+    pub fn present_answer(&mut self, character_name: &str, question: &str) -> String {
+        self.show_menu(MenuScreen {
+            title: character_name.into(),
+            body: question.into(),
+            prompt: self.language.enter_answer().into(),
+            menu: vec![],
+            command: true,
+            ..MenuScreen::default()
+        });
+        self.typed_text()
+    }
+    pub fn present_journal(&mut self, active: &[String], completed: &[String], failed: &[String]) {
+        let menu = vec![MenuItem(
+            self.language.continue_game().into(),
+            UIChoice::None,
+        )];
+        let body = [
+            (self.language.quests_active(), active),
+            (self.language.quests_completed(), completed),
+            (self.language.quests_failed(), failed),
+        ]
+        .into_iter()
+        .filter(|(_, quests)| !quests.is_empty())
+        .map(|(heading, quests)| format!("{heading}\n- {}", quests.join("\n- ")))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+        self.show_menu(MenuScreen {
+            title: self.language.journal().clone(),
+            body,
+            prompt: "".into(),
+            menu,
+            ..MenuScreen::default()
+        });
+    }
+    pub fn present_chat_history(&mut self, character_name: &str, history: &[String]) {
+        let menu = vec![MenuItem(
+            self.language.continue_game().into(),
+            UIChoice::None,
+        )];
+        self.show_menu(MenuScreen {
+            title: character_name.into(),
+            body: history.join("\n\n"),
+            prompt: "".into(),
+            menu,
+            ..MenuScreen::default()
+        });
+    }
     pub fn present_inventory(&mut self, items: &[String]) {
         let menu = vec![MenuItem(
             self.language.continue_game().into(),
@@ -261,6 +455,7 @@ impl UI {
             body,
             prompt: "".into(),
             menu,
+            ..MenuScreen::default()
         });
     }
     pub fn present_chat_targets(
@@ -283,6 +478,7 @@ impl UI {
             prompt: self.language.choose_chat().into(),
             body: room_description.into(),
             menu,
+            ..MenuScreen::default()
         });
         if let Some(UIState {
             choice: UIChoice::StartChat(choice),
@@ -294,17 +490,45 @@ impl UI {
             panic!("Expected character in chat select!");
         }
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn present_chat(
         &mut self,
         character_name: &str,
         dialogue: &str,
         responses: &[String],
+        chosen: &[bool],
+        exhausted: &[String],
+        can_go_back: bool,
+        has_history: bool,
+        art: Option<&str>,
     ) -> ChatChoice {
         let mut menu = responses
             .iter()
             .enumerate()
             .map(|(i, c)| MenuItem(c.into(), UIChoice::InChat(ChatChoice::RespondWith(i))))
             .collect::<Vec<_>>();
+        let dimmed = chosen
+            .iter()
+            .enumerate()
+            .filter_map(|(i, chosen)| chosen.then_some(i))
+            .collect();
+        menu.extend(
+            exhausted
+                .iter()
+                .map(|c| MenuItem(c.into(), UIChoice::Disabled)),
+        );
+        if can_go_back {
+            menu.push(MenuItem(
+                self.language.back_response().into(),
+                UIChoice::InChat(ChatChoice::Back),
+            ));
+        }
+        if has_history {
+            menu.push(MenuItem(
+                self.language.view_history().into(),
+                UIChoice::InChat(ChatChoice::ViewHistory),
+            ));
+        }
         if responses.is_empty() {
             menu.push(MenuItem(
                 self.language.cancel_response().into(),
@@ -313,9 +537,12 @@ impl UI {
         }
         self.show_menu(MenuScreen {
             title: character_name.into(),
+            art: art.unwrap_or_default().into(),
             prompt: self.language.choose_response().into(),
             body: dialogue.into(),
             menu,
+            dimmed,
+            ..MenuScreen::default()
         });
         if let Some(UIState {
             choice: UIChoice::InChat(choice),
@@ -332,6 +559,7 @@ impl UI {
         room_name: &str,
         room_description: &str,
         actions: &[String],
+        exhausted: &[String],
     ) -> InteractionChoice {
         let mut menu = actions
             .iter()
@@ -343,6 +571,12 @@ impl UI {
                 )
             })
             .collect::<Vec<_>>();
+        menu.extend(exhausted.iter().map(|c| {
+            MenuItem(
+                c.to_string().to_case(convert_case::Case::Title),
+                UIChoice::Disabled,
+            )
+        }));
         menu.push(MenuItem(
             self.language.cancel_action().into(),
             UIChoice::Interact(InteractionChoice::Nothing),
@@ -352,6 +586,7 @@ impl UI {
             prompt: self.language.choose_action().into(),
             body: room_description.into(),
             menu,
+            ..MenuScreen::default()
         });
         if let Some(UIState {
             choice: UIChoice::Interact(choice),
@@ -378,6 +613,7 @@ impl UI {
                 self.language.continue_game().into(),
                 UIChoice::None,
             )],
+            ..MenuScreen::default()
         });
     }
 
@@ -406,6 +642,7 @@ impl UI {
             prompt: self.language.choose_exit().into(),
             body: room_description.into(),
             menu,
+            ..MenuScreen::default()
         });
         if let Some(UIState {
             choice: UIChoice::Leave(choice),
@@ -417,6 +654,71 @@ impl UI {
             panic!("Expected exit direction in exit room prompt!");
         }
     }
+    fn typed_text(&mut self) -> String {
+        if let Some(UIState {
+            choice: UIChoice::Command(text),
+            ..
+        }) = self.siv.user_data::<UIState>()
+        {
+            text.clone()
+        } else {
+            panic!("Expected typed text in text prompt!");
+        }
+    }
+    fn room_body(
+        &self,
+        room_description: &str,
+        characters: &[String],
+        exits: &[String],
+        clock: Option<&str>,
+    ) -> String {
+        let mut body = String::new();
+        body.push_str(room_description);
+        body.push_str("\n\n");
+        if !characters.is_empty() {
+            body.push_str(self.language.characters_found());
+            body.push(' ');
+            body.push_str(&characters.join(", "));
+            body.push('\n');
+        }
+        if !exits.is_empty() {
+            body.push_str(self.language.exits_found());
+            body.push(' ');
+            body.push_str(
+                &exits
+                    .iter()
+                    .map(|s| s.to_string().to_case(convert_case::Case::Title))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            body.push('\n');
+        }
+        if let Some(clock) = clock {
+            body.push_str(self.language.current_time());
+            body.push(' ');
+            body.push_str(clock);
+            body.push('\n');
+        }
+        body
+    }
+    fn styled(&self, text: &str) -> StyledString {
+        let Ok(spans) = markup_spans(text) else {
+            return StyledString::plain(text);
+        };
+        let mut styled = StyledString::new();
+        for span in spans {
+            let style = span.styles.iter().fold(Style::none(), |style, s| {
+                style.combine(match s {
+                    TextStyle::Bold => Style::from(Effect::Bold),
+                    TextStyle::Italic => Style::from(Effect::Italic),
+                    TextStyle::Underline => Style::from(Effect::Underline),
+                    TextStyle::Color(slot) => Style::from(Color::from(self.theme.color(*slot))),
+                })
+            });
+            styled.append_styled(span.text, style);
+        }
+        styled
+    }
     fn swap_layer<T>(&mut self, layer: T)
     where
         T: IntoBoxedView,
@@ -429,6 +731,22 @@ impl UI {
         }
     }
     fn show_menu(&mut self, screen: MenuScreen) {
+        let screen = MenuScreen {
+            status: self.status.clone(),
+            ..screen
+        };
+        if self.screen.status != screen.status {
+            let styled = self.styled(&screen.status);
+            self.siv.call_on_name("status", |v: &mut TextView| {
+                v.set_content(styled);
+            });
+            self.siv.call_on_name(
+                "status_box",
+                |v: &mut HideableView<Layer<NamedView<TextView>>>| {
+                    v.set_visible(!screen.status.is_empty());
+                },
+            );
+        }
         if self.screen.title != screen.title {
             self.siv.call_on_name("title", |v: &mut views::TextView| {
                 let mut styled = StyledString::new();
@@ -439,34 +757,91 @@ impl UI {
                 v.set_content(styled);
             });
         }
-        if self.screen.body != screen.body {
-            self.siv.call_on_name("body", |v: &mut views::TextView| {
-                let mut styled = StyledString::new();
-                styled.append_plain(screen.body.as_str());
-                v.set_content(styled);
+        if self.screen.art != screen.art {
+            self.siv.call_on_name("art", |v: &mut views::TextView| {
+                v.set_content(screen.art.as_str());
             });
+            self.siv
+                .call_on_name("art_box", |v: &mut HideableView<LinearLayout>| {
+                    v.set_visible(!screen.art.is_empty());
+                });
+        }
+        if self.screen.body != screen.body {
+            self.reveal("body", self.styled(&screen.body));
         }
         if self.screen.prompt != screen.prompt {
+            let styled = self.styled(&screen.prompt);
             self.siv.call_on_name("prompt", |v: &mut views::TextView| {
-                let mut styled = StyledString::new();
-                styled.append_plain(screen.prompt.as_str());
                 v.set_content(styled);
             });
         }
+        let items = screen
+            .menu
+            .iter()
+            .enumerate()
+            .map(|(i, MenuItem(text, value))| {
+                let label = if value == &UIChoice::Disabled || screen.dimmed.contains(&i) {
+                    StyledString::styled(
+                        strip_markup(text),
+                        Style::from(Color::from(self.theme.subdued())),
+                    )
+                } else {
+                    self.styled(text)
+                };
+                (label, value.clone())
+            })
+            .collect::<Vec<_>>();
         self.siv
             .call_on_name("menu", |v: &mut views::SelectView<UIChoice>| {
                 v.clear();
-                for MenuItem(text, value) in &screen.menu {
-                    v.add_item(text.as_str(), value.clone());
+                for (label, value) in items {
+                    v.add_item(label, value);
                 }
             });
+        self.siv.call_on_name(
+            "command_box",
+            |v: &mut HideableView<NamedView<EditView>>| {
+                v.set_visible(screen.command);
+            },
+        );
+        let notice = self.notice(screen.command);
+        self.siv.call_on_name("notice", |v: &mut TextView| {
+            v.set_content(notice);
+        });
+        if screen.command {
+            self.siv.call_on_name("command", |v: &mut EditView| {
+                v.set_content("");
+            });
+            self.siv.focus_name("command").ok();
+        } else {
+            self.siv.focus_name("menu").ok();
+        }
         self.screen = screen;
         self.siv.run();
     }
     fn switch_to_menu_screen(&mut self) {
+        let status_style = ColorStyle::new(
+            Color::from(self.theme.status()),
+            Color::from(self.theme.status_background()),
+        );
+        let status = TextView::new(StyledString::new())
+            .h_align(HAlign::Center)
+            .style(status_style)
+            .with_name("status");
+        let status = HideableView::new(Layer::with_color(status, status_style))
+            .hidden()
+            .with_name("status_box");
         let title = TextView::new(StyledString::new())
             .h_align(HAlign::Center)
             .with_name("title");
+        let art = TextView::new(StyledString::new())
+            .no_wrap()
+            .with_name("art");
+        let art = LinearLayout::horizontal()
+            .child(DummyView.full_width())
+            .child(art)
+            .child(DummyView.full_width());
+        let art = HideableView::new(art).hidden().with_name("art_box");
         let body = TextView::new(StyledString::new())
             .h_align(HAlign::Left)
             .with_name("body");
@@ -476,27 +851,39 @@ impl UI {
             .with_name("prompt");
         let mut menu = SelectView::<UIChoice>::new();
         menu.set_on_submit(|siv, selected| {
+            if selected == &UIChoice::Disabled {
+                return;
+            }
             siv.with_user_data(|data: &mut UIState| {
                 data.choice = selected.clone();
             });
             siv.quit();
         });
         let menu = menu.with_name("menu");
-        let mut notice = StyledString::new();
-        notice.append_styled(
-            self.language.press_q_to_quit(),
-            Style::from(Color::from(self.theme.subdued())),
-        );
-        let notice = TextView::new(notice).h_align(HAlign::Center);
+        let command = EditView::new()
+            .on_submit(|siv, text| {
+                let text = text.to_string();
+                siv.with_user_data(|data: &mut UIState| {
+                    data.choice = UIChoice::Command(text);
+                });
+                siv.quit();
+            })
+            .with_name("command");
+        let command = HideableView::new(command).hidden().with_name("command_box");
+        let notice = TextView::new(self.notice(false))
+            .h_align(HAlign::Center)
+            .with_name("notice");
         let layout = LinearLayout::vertical()
             .child(DummyView.full_height())
             .weight(1)
             .child(title)
             .child(DummyView.fixed_height(1))
+            .child(art)
             .child(body)
             .child(DummyView.fixed_height(1))
             .child(prompt)
             .child(menu)
+            .child(command)
             .child(DummyView.full_height())
             .weight(1)
             .child(notice)
@@ -507,10 +894,150 @@ impl UI {
             .child(layout)
             .child(DummyView.full_width())
             .weight(1);
+        let layout = LinearLayout::vertical().child(status).child(layout);
         self.swap_layer(layout);
     }
+    fn reveal(&mut self, view: &'static str, text: StyledString) {
+        let speed = self.theme.text_speed().map_or(0, |s| s.chars_per_second());
+        let length = text.spans().map(|s| s.content.chars().count()).sum();
+        let typewriter = Typewriter::new(SystemClock, length, speed);
+        if typewriter.is_done() {
+            self.siv.call_on_name(view, |v: &mut TextView| {
+                v.set_content(text);
+            });
+            return;
+        }
+        let reveal = Reveal {
+            view,
+            text,
+            hidden: Style::from(Color::from(self.theme.background())),
+            typewriter,
+        };
+        self.siv.with_user_data(|data: &mut UIState| {
+            data.reveal = Some(reveal);
+        });
+        self.revealing.store(true, Ordering::Relaxed);
+        self.siv.set_fps(REVEAL_FPS);
+        Self::step_reveal(&mut self.siv, &self.revealing);
+    }
+    fn step_reveal(siv: &mut Cursive, revealing: &AtomicBool) {
+        let Some(reveal) = siv.user_data::<UIState>().and_then(|d| d.reveal.clone()) else {
+            return;
+        };
+        let shown = reveal.typewriter.revealed();
+        siv.call_on_name(reveal.view, |v: &mut TextView| {
+            v.set_content(partially_revealed(&reveal.text, shown, reveal.hidden));
+        });
+        if reveal.typewriter.is_done() {
+            siv.with_user_data(|data: &mut UIState| {
+                data.reveal = None;
+            });
+            revealing.store(false, Ordering::Relaxed);
+            siv.set_fps(0);
+        }
+    }
+    fn skip_reveal(siv: &mut Cursive, revealing: &AtomicBool) {
+        siv.with_user_data(|data: &mut UIState| {
+            if let Some(reveal) = &mut data.reveal {
+                reveal.typewriter.skip();
+            }
+        });
+        Self::step_reveal(siv, revealing);
+    }
+    fn toggle_log(siv: &mut Cursive) {
+        if siv.screen().len() > 1 {
+            siv.pop_layer();
+            return;
+        }
+        let Some(log) = siv.user_data::<UIState>().map(|data| data.log.clone()) else {
+            return;
+        };
+        if log.text.is_empty() {
+            return;
+        }
+        let title = TextView::new(log.title).h_align(HAlign::Center);
+        let text =
+            ScrollView::new(TextView::new(log.text)).scroll_strategy(ScrollStrategy::StickToBottom);
+        let close = Button::new_raw(format!("[ {} ]", log.close), |s| {
+            s.pop_layer();
+        });
+        let layout = LinearLayout::vertical()
+            .child(DummyView.full_height())
+            .weight(1)
+            .child(title)
+            .child(DummyView.fixed_height(1))
+            .child(text)
+            .child(DummyView.fixed_height(1))
+            .child(close)
+            .child(DummyView.full_height())
+            .weight(1);
+        let layout = LinearLayout::horizontal()
+            .child(DummyView.full_width())
+            .weight(1)
+            .child(layout)
+            .child(DummyView.full_width())
+            .weight(1);
+        siv.add_fullscreen_layer(layout);
+    }
+    fn notice(&self, typing: bool) -> StyledString {
+        let (quit, log) = if typing {
+            (
+                self.language.press_esc_to_quit(),
+                self.language.press_ctrl_l_for_log(),
+            )
+        } else {
+            (
+                self.language.press_q_to_quit(),
+                self.language.press_l_for_log(),
+            )
+        };
+        StyledString::styled(
+            format!("{quit} {log}"),
+            Style::from(Color::from(self.theme.subdued())),
+        )
+    }
+    fn art_view(art: &str) -> LinearLayout {
+        LinearLayout::horizontal()
+            .child(DummyView.full_width())
+            .child(TextView::new(art).no_wrap())
+            .child(DummyView.full_width())
+    }
     fn pause_for_any_key_view(&self) -> Button {
         let text = format!("[ {} ]", self.language.continue_game());
         Button::new_raw(text, |s| s.quit())
     }
 }
+
+const fn is_keypress(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Char(_)
+            | Event::CtrlChar(_)
+            | Event::AltChar(_)
+            | Event::Key(_)
+            | Event::Shift(_)
+            | Event::Alt(_)
+            | Event::AltShift(_)
+            | Event::Ctrl(_)
+            | Event::CtrlShift(_)
+            | Event::CtrlAlt(_)
+    )
+}
+
+// The hidden rest keeps its place so the text doesn't reflow as it appears.
+fn partially_revealed(text: &StyledString, shown: usize, hidden: Style) -> StyledString {
+    let mut revealed = StyledString::new();
+    let mut left = shown;
+    for span in text.spans() {
+        let split = span
+            .content
+            .char_indices()
+            .nth(left)
+            .map_or(span.content.len(), |(i, _)| i);
+        let (visible, rest) = span.content.split_at(split);
+        left -= visible.chars().count();
+        revealed.append_styled(visible, *span.attr);
+        revealed.append_styled(rest, hidden);
+    }
+    revealed
+}